@@ -438,6 +438,7 @@ impl fmt::Display for CannotReallocInPlace {
 ///   this trait are allowed to rely on the contracts defined on each method,
 ///   and implementors must ensure such contracts remain true.
 #[stable(feature = "global_alloc", since = "1.28.0")]
+#[cfg_attr(not(stage0), lang = "global_alloc")]
 pub unsafe trait GlobalAlloc {
     /// Allocate memory as described by the given `layout`.
     ///
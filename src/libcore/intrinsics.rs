@@ -697,6 +697,14 @@ extern "rust-intrinsic" {
     /// crate it is invoked in.
     pub fn type_id<T: ?Sized + 'static>() -> u64;
 
+    /// Gets the location of the call this is in, or of the call to the
+    /// enclosing function if it is `#[track_caller]`.
+    ///
+    /// The stabilized version of this intrinsic is
+    /// [`std::panic::Location::caller`](../../std/panic/struct.Location.html#method.caller).
+    #[cfg(not(stage0))]
+    pub fn caller_location() -> &'static ::panic::Location<'static>;
+
     /// Creates a value initialized to zero.
     ///
     /// `init` is unsafe because it returns a zeroed-out datum,
@@ -172,6 +172,7 @@ impl<'a> fmt::Display for PanicInfo<'a> {
 ///
 /// panic!("Normal panic");
 /// ```
+#[cfg_attr(not(stage0), lang = "panic_location")]
 #[derive(Debug)]
 #[stable(feature = "panic_hooks", since = "1.10.0")]
 pub struct Location<'a> {
@@ -257,6 +258,31 @@ impl<'a> Location<'a> {
     }
 }
 
+#[cfg(not(stage0))]
+impl<'a> Location<'a> {
+    /// Returns the location of the call to the enclosing function if it is
+    /// `#[track_caller]`, or of this call otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(track_caller)]
+    /// use std::panic::Location;
+    ///
+    /// #[track_caller]
+    /// fn whence() -> &'static Location<'static> {
+    ///     Location::caller()
+    /// }
+    ///
+    /// assert_eq!(whence().line(), line!());
+    /// ```
+    #[unstable(feature = "track_caller", issue = "47809")]
+    #[track_caller]
+    pub const fn caller() -> &'static Location<'static> {
+        unsafe { ::intrinsics::caller_location() }
+    }
+}
+
 #[stable(feature = "panic_hook_display", since = "1.26.0")]
 impl<'a> fmt::Display for Location<'a> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -476,6 +476,9 @@ define_dep_nodes!( <'tcx>
     [eval_always] CoherenceInherentImplOverlapCheck,
     [] CoherenceCheckTrait(DefId),
     [eval_always] PrivacyAccessLevels(CrateNum),
+    [eval_always] CheckCrateAttrs(CrateNum),
+    [eval_always] AttrCheckResults(CrateNum),
+    [] CheckModAttrs(DefId),
 
     // Represents the MIR for a fn; also used as the task node for
     // things read/modify that MIR.
@@ -1428,6 +1428,37 @@ fn main() {
 ```
 "##,
 
+E0458: r##"
+An unknown "kind" was specified for a link attribute. Erroneous code example:
+
+```ignore (cannot-test-this-because-rustdoc-stops-compile-fail-before-codegen)
+#[link(kind = "wonderful_unicorn")] extern {}
+// error: unknown kind: `wonderful_unicorn`
+```
+
+Please specify a valid "kind" value, from one of the following:
+
+* static
+* dylib
+* framework
+"##,
+
+E0459: r##"
+A link was used without a name parameter. Erroneous code example:
+
+```ignore (cannot-test-this-because-rustdoc-stops-compile-fail-before-codegen)
+#[link(kind = "dylib")] extern {}
+// error: #[link(...)] specified without `name = "foo"`
+```
+
+Please add the name parameter to allow the rust compiler to find the library
+you want. Example:
+
+```no_run
+#[link(kind = "dylib", name = "some_lib")] extern {} // ok!
+```
+"##,
+
 E0478: r##"
 A lifetime bound was not satisfied.
 
@@ -1699,6 +1730,29 @@ To understand better how closures work in Rust, read:
 https://doc.rust-lang.org/book/first-edition/closures.html
 "##,
 
+E0558: r##"
+The `export_name` attribute was malformed.
+
+Erroneous code example:
+
+```compile_fail,E0558
+#[export_name] // error: attribute must be of the form `#[export_name = "name"]`
+pub fn something() {}
+
+fn main() {}
+```
+
+The `export_name` attribute expects a string in order to determine the name of
+the exported symbol. Example:
+
+```
+#[export_name = "some_function"] // ok!
+pub fn something() {}
+
+fn main() {}
+```
+"##,
+
 E0580: r##"
 The `main` function was incorrectly declared.
 
@@ -1837,6 +1891,37 @@ makes a difference in practice.)
 [rfc401]: https://github.com/rust-lang/rfcs/blob/master/text/0401-coercions.md
 "##,
 
+E0587: r##"
+A type has both `packed` and `align` representation hints.
+
+Erroneous code example:
+
+```compile_fail,E0587
+#[repr(packed, align(8))] // error: conflicting packed and align hints
+struct Umbrella(i32);
+```
+
+`packed` lowers the alignment of a type, so that its fields can be laid out
+without padding, while `align` raises it. A type cannot be both minimally and
+maximally aligned, so only one of the two hints may be used, and this holds
+even when they are written in separate `#[repr]` attributes:
+
+```
+#[repr(packed)] // ok!
+struct Umbrella(i32);
+```
+
+To give a packed type a larger alignment, wrap it in an aligned type instead:
+
+```
+#[repr(packed)]
+struct Umbrella(i32);
+
+#[repr(align(8))] // ok!
+struct AlignedUmbrella(Umbrella);
+```
+"##,
+
 E0593: r##"
 You tried to supply an `Fn`-based type with an incorrect number of arguments
 than what was expected.
@@ -1918,6 +2003,30 @@ fn foo<'a>(x: &'a i32, y: &i32) -> &'a i32 {
 ```
 "##,
 
+E0634: r##"
+A type was given more than one packed representation hint, asking for different
+packings.
+
+Erroneous code example:
+
+```compile_fail,E0634
+#![feature(repr_packed)]
+
+#[repr(packed, packed(2))] // error!
+struct Foo(u64);
+```
+
+A type is packed to a single alignment. Keep the hint that asks for the one you
+want:
+
+```
+#![feature(repr_packed)]
+
+#[repr(packed(2))]
+struct Foo(u64);
+```
+"##,
+
 E0644: r##"
 A closure or generator was constructed that references its own type.
 
@@ -1951,6 +2060,19 @@ reference its own *type*.
 
 "##,
 
+E0648: r##"
+`export_name`, `link_name` and `link_section` attributes may not contain null
+characters (`\0`).
+
+```compile_fail,E0648
+#[export_name="\0foo"] // error: `export_name` may not contain null characters
+pub fn bar() {}
+```
+
+Symbol and section names are passed to the linker as null-terminated strings,
+so a null character would silently cut the name short.
+"##,
+
 E0692: r##"
 A `repr(transparent)` type was also annotated with other, incompatible
 representation hints.
@@ -2052,7 +2174,7 @@ where 'x: 'y
 
 E0701: r##"
 This error indicates that a `#[non_exhaustive]` attribute was incorrectly placed
-on something other than a struct or enum.
+on something other than a struct, enum or enum variant.
 
 Examples of erroneous code:
 
@@ -2078,6 +2200,659 @@ struct Foo;
 ```
 "##,
 
+E0712: r##"
+A `#[no_mangle]` attribute was applied to something other than a function or
+static.
+
+Erroneous code example:
+
+```compile_fail,E0712
+#![deny(misplaced_inert_attributes)]
+
+#[no_mangle] // error!
+struct Foo;
+```
+
+Only functions and statics have a symbol that can be exported under an
+unmangled name. Closures are anonymous, so there is nothing to export either;
+move the body into a named function instead:
+
+```
+#[no_mangle]
+pub extern fn foo() {}
+```
+
+Anywhere else it has no effect. This is reported by the
+`misplaced_inert_attributes` lint, which is warn-by-default.
+"##,
+
+E0713: r##"
+A `#[cold]` attribute was applied to something other than a function or a
+closure.
+
+Erroneous code example:
+
+```compile_fail,E0713
+#![deny(misplaced_inert_attributes)]
+
+#[cold] // error!
+struct Foo;
+```
+
+`#[cold]` marks code that is unlikely to be called, which only makes sense for
+functions and closures:
+
+```
+#[cold]
+fn unlikely() {}
+```
+
+Anywhere else it has no effect. This is reported by the
+`misplaced_inert_attributes` lint, which is warn-by-default.
+"##,
+
+E0714: r##"
+A `#[used]` attribute was applied to something other than a static.
+
+Erroneous code example:
+
+```compile_fail,E0714
+#![feature(used)]
+
+#[used]
+fn foo() {} // error!
+```
+
+`#[used]` keeps a static in the object file even if it is unreferenced, so it
+can only be applied to statics:
+
+```
+#![feature(used)]
+
+#[used]
+static FOO: u32 = 0;
+```
+"##,
+
+E0715: r##"
+A `#[target_feature]` attribute was applied to something other than a function.
+
+Erroneous code example:
+
+```compile_fail,E0715
+#[target_feature(enable = "sse2")]
+struct Foo; // error!
+```
+
+Target features change how a function is compiled, so the attribute can only
+be applied to functions.
+"##,
+
+E0716: r##"
+A `#[wasm_import_module]` attribute was applied to something other than a
+foreign module.
+
+Erroneous code example:
+
+```compile_fail,E0716
+#![feature(wasm_import_module)]
+
+#[wasm_import_module = "foo"]
+fn foo() {} // error!
+```
+
+The attribute names the module that the items of an `extern` block are
+imported from, so it must be placed on the `extern` block:
+
+```
+#![feature(wasm_import_module)]
+
+#[wasm_import_module = "foo"]
+extern {
+    fn foo();
+}
+```
+"##,
+
+E0717: r##"
+A representation hint was given arguments it does not take.
+
+Erroneous code examples:
+
+```compile_fail,E0717
+#[repr(C(1))] // error: `C` representation hint takes no arguments
+struct Foo {
+    x: u8,
+}
+
+#[repr(align)] // error: `align` representation hint needs an argument
+struct Bar {
+    x: u8,
+}
+```
+
+`C`, `simd`, `transparent` and the integer representations are written as bare
+words. `packed` optionally takes the maximum alignment in parentheses, and
+`align` requires the alignment in parentheses:
+
+```
+#[repr(C)]
+struct Foo {
+    x: u8,
+}
+
+#[repr(align(8))]
+struct Bar {
+    x: u8,
+}
+```
+"##,
+
+E0718: r##"
+A `#[must_use]` attribute was applied to something other than a function, a
+struct, enum or union, or a trait.
+
+Erroneous code example:
+
+```compile_fail,E0718
+#![deny(misplaced_inert_attributes)]
+
+trait Foo {
+    #[must_use] // error!
+    type Output;
+}
+```
+
+`#[must_use]` can be applied to functions and methods, to structs, enums and
+unions, and to traits, and has no effect anywhere else. This is reported by the
+`misplaced_inert_attributes` lint, which is warn-by-default.
+
+An associated type only names a type, so put the attribute on the definition of
+that type, or on the trait:
+
+```
+#[must_use]
+trait Foo {
+    type Output;
+}
+```
+"##,
+
+E0719: r##"
+A function was given codegen attributes that cannot be honoured together.
+
+Erroneous code example:
+
+```compile_fail,E0719
+#![feature(naked_functions)]
+
+#[inline]
+#[naked]
+extern "C" fn foo() {} // error!
+```
+
+All the conflicts on a function are listed in a single error. The combinations
+rejected are:
+
+* `#[inline]` or `#[inline(always)]` with `#[naked]`, as a naked function has no
+  prologue or epilogue and cannot be inlined into its callers.
+* `#[inline(always)]` with `#[target_feature]`, as a function using target
+  features may only be inlined into callers that enable the same features.
+
+Remove one of the attributes in each pair.
+"##,
+
+E0720: r##"
+An attribute that only applies to items was written as a crate attribute.
+
+Erroneous code example:
+
+```compile_fail,E0720
+#![inline] // error!
+
+fn main() {}
+```
+
+An inner attribute at the top of the crate root applies to the crate as a
+whole, not to the first item that follows it. Write the attribute as an outer
+attribute on the item it is meant for instead:
+
+```
+#[inline]
+fn foo() {}
+
+fn main() {}
+```
+"##,
+
+E0721: r##"
+A function was marked both `#[inline(always)]` and `#[inline(never)]`.
+
+Erroneous code example:
+
+```compile_fail,E0721
+#[inline(always)]
+#[inline(never)] // error!
+fn foo() {}
+```
+
+The two hints ask for opposite things, so at most one of them can be followed.
+Keep the one that says what you want:
+
+```
+#[inline(never)]
+fn foo() {}
+```
+"##,
+
+E0722: r##"
+The argument of `#[repr(align)]` or `#[repr(packed)]` was not an unsuffixed
+integer.
+
+Erroneous code example:
+
+```compile_fail,E0722
+#[repr(align(8u32))] // error!
+struct Foo(u64);
+```
+
+Write the alignment in bytes as a plain integer:
+
+```
+#[repr(align(8))]
+struct Foo(u64);
+```
+"##,
+
+E0723: r##"
+The argument of `#[repr(align)]` or `#[repr(packed)]` was not a power of two.
+
+Erroneous code example:
+
+```compile_fail,E0723
+#[repr(align(12))] // error!
+struct Foo(u64);
+```
+
+Every alignment is a power of two. For `align`, use the next one up to get at
+least the alignment you wanted:
+
+```
+#[repr(align(16))]
+struct Foo(u64);
+```
+"##,
+
+E0724: r##"
+The argument of `#[repr(align)]` or `#[repr(packed)]` was larger than the
+largest alignment that is supported.
+
+Erroneous code example:
+
+```compile_fail,E0724
+#[repr(align(1073741824))] // error!
+struct Foo(u64);
+```
+
+No alignment larger than 2^29 bytes is supported, and some targets support
+even less for `align`. Use a smaller alignment:
+
+```
+#[repr(align(4096))]
+struct Foo(u64);
+```
+"##,
+
+E0726: r##"
+An `#[export_name]` attribute was applied to something other than a function
+or static.
+
+Erroneous code example:
+
+```compile_fail,E0726
+#![deny(misplaced_inert_attributes)]
+
+#[export_name = "foo"] // error!
+struct Foo;
+```
+
+Only functions and statics have a symbol to export under the given name:
+
+```
+#[export_name = "foo"]
+pub extern fn foo() {}
+```
+
+Anywhere else it has no effect. This is reported by the
+`misplaced_inert_attributes` lint, which is warn-by-default.
+"##,
+
+E0727: r##"
+A `#[link_name]` attribute was applied to something other than a function or
+static in an `extern` block.
+
+Erroneous code example:
+
+```compile_fail,E0727
+#![deny(misplaced_inert_attributes)]
+
+#[link_name = "foo"] // error!
+pub extern fn foo() {}
+```
+
+`#[link_name]` gives the name of the symbol a foreign function or static is
+imported from:
+
+```
+extern {
+    #[link_name = "foo"]
+    fn imported_foo();
+}
+```
+
+Anywhere else it has no effect. This is reported by the
+`misplaced_inert_attributes` lint, which is warn-by-default. To export a
+function or static defined in Rust under a different name, use
+`#[export_name]` instead:
+
+```
+#[export_name = "foo"]
+pub extern fn foo() {}
+```
+"##,
+
+E0728: r##"
+A `#[link_section]` attribute was applied to something other than a function or
+static.
+
+Erroneous code example:
+
+```compile_fail,E0728
+#![deny(misplaced_inert_attributes)]
+
+#[link_section = ".example_section"] // error!
+struct Foo;
+```
+
+Only functions and statics are placed in a section of the object file:
+
+```
+#[link_section = ".example_section"]
+pub static VAR1: u32 = 1;
+```
+
+Anywhere else it has no effect. This is reported by the
+`misplaced_inert_attributes` lint, which is warn-by-default.
+"##,
+
+E0729: r##"
+A `#[thread_local]` attribute was applied to something other than a static.
+
+Erroneous code example:
+
+```compile_fail,E0729
+#![feature(thread_local)]
+
+#[thread_local] // error!
+fn foo() {}
+```
+
+`#[thread_local]` gives a static, or a static in an `extern` block, a separate
+copy for each thread:
+
+```
+#![feature(thread_local)]
+
+#[thread_local]
+static FOO: u32 = 0;
+```
+
+The attribute is unstable. The `thread_local!` macro in the standard library
+declares thread-local values on stable Rust.
+"##,
+
+E0730: r##"
+A `#[naked]` attribute was applied to something other than a function.
+
+Erroneous code example:
+
+```compile_fail,E0730
+#![feature(naked_functions, stmt_expr_attributes)]
+
+fn main() {
+    let f = #[naked] || {}; // error!
+}
+```
+
+Only a function can be emitted without a prologue and an epilogue. A closure
+captures its environment through an argument whose layout is chosen by the
+compiler, so its body could not be written by hand.
+"##,
+
+E0731: r##"
+A `#[naked]` function was declared without an explicit ABI.
+
+Erroneous code example:
+
+```compile_fail,E0731
+#![feature(naked_functions)]
+
+#[naked]
+fn foo() {} // error!
+```
+
+A naked function has no prologue or epilogue, so its body has to find the
+arguments, and leave the return value, where the calling convention puts them.
+The Rust ABI is unspecified, and may change between compiler versions. Declare
+the function with the calling convention its body is written for:
+
+```
+#![feature(naked_functions)]
+
+#[naked]
+extern "C" fn foo() {}
+```
+"##,
+
+E0732: r##"
+A `#[track_caller]` attribute was applied to something other than a function.
+
+Erroneous code example:
+
+```compile_fail,E0732
+#![feature(track_caller)]
+
+#[track_caller]
+struct Foo; // error!
+```
+
+Only a call to a function can pass the location of its caller. Put the
+attribute on the functions that use `Location::caller()` instead.
+"##,
+
+E0733: r##"
+A `#[track_caller]` function was declared with an ABI other than the Rust ABI.
+
+Erroneous code example:
+
+```compile_fail,E0733
+#![feature(track_caller)]
+
+#[track_caller]
+extern "C" fn foo() {} // error!
+```
+
+A `#[track_caller]` function is passed the location of its caller as an
+implicit argument, after the ones it declares. Other calling conventions have
+no room for such an argument, so callers written in other languages could not
+pass it. Use the Rust ABI:
+
+```
+#![feature(track_caller)]
+
+#[track_caller]
+fn foo() {}
+```
+"##,
+
+E0734: r##"
+A `#[track_caller]` attribute was applied to a trait method.
+
+Erroneous code example:
+
+```compile_fail,E0734
+#![feature(track_caller)]
+
+trait Foo {
+    #[track_caller]
+    fn foo(&self); // error!
+}
+```
+
+A trait method may be called through a trait object, which only knows the
+signature of the method and so cannot pass the location of the caller. Call a
+separate `#[track_caller]` function from the method instead.
+"##,
+
+
+E0736: r##"
+A `#[macro_export]` attribute was applied to something other than a
+`macro_rules!` definition.
+
+Erroneous code example:
+
+```compile_fail,E0736
+#![deny(misplaced_inert_attributes)]
+
+#[macro_export] // error!
+fn foo() {}
+```
+
+Only `macro_rules!` definitions can be exported from a crate this way:
+
+```
+#[macro_export]
+macro_rules! foo {
+    () => {};
+}
+```
+
+Anywhere else it has no effect. This is reported by the
+`misplaced_inert_attributes` lint, which is warn-by-default.
+"##,
+
+E0737: r##"
+A `#[path]` attribute was applied to something other than a module.
+
+Erroneous code example:
+
+```compile_fail,E0737
+#![deny(misplaced_inert_attributes)]
+
+#[path = "foo.rs"] // error!
+fn foo() {}
+```
+
+`#[path]` sets the file a `mod` declaration loads the module from:
+
+```ignore (cannot-doctest-multifile-project)
+#[path = "foo.rs"]
+mod foo;
+```
+
+Anywhere else it has no effect. This is reported by the
+`misplaced_inert_attributes` lint, which is warn-by-default.
+"##,
+
+E0738: r##"
+A `#[no_link]` attribute was applied to something other than an `extern crate`
+item.
+
+Erroneous code example:
+
+```compile_fail,E0738
+#![deny(misplaced_inert_attributes)]
+
+#[no_link] // error!
+fn foo() {}
+```
+
+`#[no_link]` keeps an `extern crate` item from linking the crate it loads, so
+that only its macros are used:
+
+```ignore (cannot-doctest-external-crate)
+#[no_link]
+#[macro_use]
+extern crate foo;
+```
+
+Anywhere else it has no effect. This is reported by the
+`misplaced_inert_attributes` lint, which is warn-by-default.
+"##,
+
+E0739: r##"
+A `#[marker]` attribute was applied to something other than a trait.
+
+Erroneous code example:
+
+```compile_fail,E0739
+#![feature(marker_trait_attr)]
+
+#[marker] // error!
+struct Foo;
+```
+
+`#[marker]` lets the impls of a trait overlap, and so only applies to traits:
+
+```
+#![feature(marker_trait_attr)]
+
+#[marker]
+trait Marker {}
+```
+"##,
+
+E0741: r##"
+A `#[global_allocator]` attribute was applied to something other than a static.
+
+Erroneous code example:
+
+```compile_fail,E0741
+use std::alloc::System;
+
+#[global_allocator] // error!
+fn allocator() -> System { System }
+```
+
+The global allocator is a static whose type implements `GlobalAlloc`, which
+the allocation functions of the standard library call into:
+
+```
+use std::alloc::System;
+
+#[global_allocator]
+static GLOBAL: System = System;
+```
+"##,
+
+E0742: r##"
+An attribute of a tool was applied to something other than the targets a
+plugin registered for it.
+
+Erroneous code example:
+
+```ignore (requires-plugin)
+#![feature(plugin, tool_attributes)]
+#![plugin(my_plugin)] // registers `clippy::author` for functions only
+
+#[clippy::author] // error!
+struct Foo;
+```
+
+`rustc` doesn't check what the attributes of a tool are applied to, unless a
+plugin registered their targets with `Registry::register_attribute_targets`.
+Put the attribute on one of those targets, or remove it.
+"##,
 }
 
 
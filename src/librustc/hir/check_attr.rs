@@ -13,25 +13,75 @@
 //! attached to items that actually support them and if there are
 //! conflicts between multiple such attributes attached to the same
 //! item.
+//!
+//! Every misplaced attribute found is also recorded as an `AttrCheckResult`,
+//! so that tools driving the compiler can consume them without parsing
+//! diagnostics. The `attr_check_results` query collects them for the whole
+//! crate; it is only computed when such a tool asks for it.
+//!
+//! The items of each module are checked by its own `check_mod_attrs` query,
+//! whose result is cached across incremental sessions: a module that did not
+//! change is not checked again, and the diagnostics it produced are replayed
+//! from the cache. Only the checks that span the whole crate run every time.
 
-use syntax_pos::Span;
-use ty::TyCtxt;
+use hir::def_id::{CrateNum, DefId, LOCAL_CRATE};
+use lint;
+use lint::builtin::{BAD_REPR, CONFLICTING_REPR_HINTS, INLINE_ALWAYS_EXPORTED,
+                    INLINE_ALWAYS_IN_NO_BUILTINS, INLINE_AND_COLD,
+                    INLINE_ON_REQUIRED_TRAIT_METHODS, INVALID_SYMBOL_NAMES,
+                    MISPLACED_INERT_ATTRIBUTES,
+                    MULTIPLE_REPR_ATTRIBUTES,
+                    NO_MANGLE_GENERIC_ITEMS, NO_MANGLE_STATIC_MUT,
+                    NON_EXHAUSTIVE_STABLE_REPR, NON_EXHAUSTIVE_UNIT_STRUCTS,
+                    PACKED_DERIVE_REFERENCES, REPR_C_DATA_ENUMS_WITH_INT, UNREFERENCED_USED_STATICS,
+                    USED_WITHOUT_LINK_SECTION, ZERO_SIZED_REPR_C};
+use errors::{Applicability, DiagnosticBuilder, DiagnosticId};
+use rustc_data_structures::sync::Lrc;
+use rustc_target::spec::abi::Abi;
+use syntax::ast::{self, Name, NodeId, CRATE_NODE_ID};
+use syntax::attr::{self, InlineAttr};
+use syntax::feature_gate::{GateIssue, emit_feature_err};
+use syntax::symbol::Symbol;
+use syntax::util::lev_distance::find_best_match_for_name;
+use syntax_pos::{BytePos, MultiSpan, Span};
+use syntax_pos::hygiene::{ExpnFormat, ExpnInfo, SyntaxContext};
+use traits;
+use ty::{self, TyCtxt, TypeFoldable};
+use ty::query::Providers;
 
 use hir;
+use hir::def::Def;
 use hir::intravisit::{self, Visitor, NestedVisitorMap};
+use middle::weak_lang_items;
+use util::nodemap::DefIdSet;
 
-#[derive(Copy, Clone, PartialEq)]
-enum Target {
+use std::cell::RefCell;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, RustcEncodable, RustcDecodable)]
+pub enum Target {
     Fn,
     Struct,
     Union,
     Enum,
     Const,
+    ExternCrate,
+    Mod,
     ForeignMod,
+    ForeignFn,
+    ForeignStatic,
+    ForeignTy,
     Expression,
     Statement,
+    Local,
     Closure,
     Static,
+    TyAlias,
+    Arm,
+    Variant,
+    Field,
+    Trait,
+    MacroDef,
+    Crate,
     Other,
 }
 
@@ -43,47 +93,589 @@ impl Target {
             hir::ItemUnion(..) => Target::Union,
             hir::ItemEnum(..) => Target::Enum,
             hir::ItemConst(..) => Target::Const,
+            hir::ItemExternCrate(..) => Target::ExternCrate,
+            hir::ItemMod(..) => Target::Mod,
             hir::ItemForeignMod(..) => Target::ForeignMod,
             hir::ItemStatic(..) => Target::Static,
+            hir::ItemTy(..) => Target::TyAlias,
+            hir::ItemTrait(..) => Target::Trait,
             _ => Target::Other,
         }
     }
+
+    fn from_impl_item(impl_item: &hir::ImplItem) -> Target {
+        match impl_item.node {
+            hir::ImplItemKind::Const(..) => Target::Const,
+            hir::ImplItemKind::Method(..) => Target::Fn,
+            hir::ImplItemKind::Type(..) => Target::TyAlias,
+        }
+    }
+
+    fn from_trait_item(trait_item: &hir::TraitItem) -> Target {
+        match trait_item.node {
+            hir::TraitItemKind::Const(..) => Target::Const,
+            hir::TraitItemKind::Method(..) => Target::Fn,
+            hir::TraitItemKind::Type(..) => Target::TyAlias,
+        }
+    }
+
+    fn from_foreign_item(foreign_item: &hir::ForeignItem) -> Target {
+        match foreign_item.node {
+            hir::ForeignItemFn(..) => Target::ForeignFn,
+            hir::ForeignItemStatic(..) => Target::ForeignStatic,
+            hir::ForeignItemType => Target::ForeignTy,
+        }
+    }
+}
+
+/// How a misplaced attribute was reported.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, RustcEncodable, RustcDecodable)]
+pub enum AttrCheckOutcome {
+    /// A hard error was emitted.
+    Error,
+    /// A warning or lint was emitted.
+    Warning,
+    /// The lint the problem is reported through is allowed, so nothing was emitted.
+    Allowed,
+}
+
+/// The targets an attribute of a tool may be applied to, as registered by a
+/// plugin. Attributes of a tool are otherwise never checked against their
+/// target.
+#[derive(Clone, Debug)]
+pub struct ToolAttrTargets {
+    /// The whole path of the attribute, like `clippy::author`.
+    pub path: String,
+    /// The targets the attribute may be applied to.
+    pub targets: Vec<Target>,
+    /// What the attribute should be applied to, as the error says it.
+    pub applies_to: String,
+}
+
+/// An attribute that was found attached to a target that does not support it.
+#[derive(Clone, Debug, RustcEncodable, RustcDecodable)]
+pub struct AttrCheckResult {
+    /// The path of the attribute, e.g. `inline`, `repr` or `clippy::author`.
+    pub attr_name: Name,
+    /// The item the attribute is attached to. For attributes on statements
+    /// and expressions this is the enclosing item.
+    pub def_id: DefId,
+    pub target: Target,
+    pub outcome: AttrCheckOutcome,
+}
+
+/// What checking the attributes of the items of a single module found: the
+/// misplaced attributes it reported, and what the checks spanning the whole
+/// crate need to know about those items.
+#[derive(Clone, Debug, Default, RustcEncodable, RustcDecodable)]
+pub struct ItemAttrChecks {
+    pub results: Vec<AttrCheckResult>,
+    /// Every use of an attribute in `BUILTIN_ATTRS` with `DuplicateScope::Crate`.
+    pub crate_singletons: Vec<(NodeId, hir::Attribute, Target)>,
+    /// `#[used]` statics that are neither exported nor placed in a section,
+    /// with the span of their `#[used]`.
+    pub unexported_used_statics: Vec<(NodeId, Span)>,
+    /// Every static a path in a body refers to.
+    pub referenced_statics: DefIdSet,
+}
+
+/// Where an attribute that may only be written once has to be unique.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum DuplicateScope {
+    /// At most once on each item. The copies are redundant, so only warn.
+    Item,
+    /// At most once in the whole crate.
+    Crate,
+}
+
+/// The forms a builtin attribute may be written in.
+#[derive(Copy, Clone)]
+struct AttributeTemplate {
+    /// Whether it may be written on its own, like `#[used]`.
+    word: bool,
+    /// What goes in `#[name(...)]`, if that form is accepted.
+    list: Option<&'static str>,
+    /// What goes in `#[name = "..."]`, if that form is accepted.
+    name_value_str: Option<&'static str>,
+}
+
+impl AttributeTemplate {
+    /// Whether `attr` is written in one of the forms of the template.
+    fn matches(&self, attr: &hir::Attribute) -> bool {
+        match attr.meta().map(|meta| meta.node) {
+            Some(ast::MetaItemKind::Word) => self.word,
+            Some(ast::MetaItemKind::List(_)) => self.list.is_some(),
+            Some(ast::MetaItemKind::NameValue(lit)) => match lit.node {
+                ast::LitKind::Str(..) => self.name_value_str.is_some(),
+                _ => false,
+            },
+            None => false,
+        }
+    }
+
+    /// The forms of the template for the attribute `name`, like
+    /// "`#[inline]` or `#[inline(always|never)]`".
+    fn describe(&self, name: &str) -> String {
+        let mut forms = vec![];
+        if self.word {
+            forms.push(format!("`#[{}]`", name));
+        }
+        if let Some(list) = self.list {
+            forms.push(format!("`#[{}({})]`", name, list));
+        }
+        if let Some(value) = self.name_value_str {
+            forms.push(format!("`#[{} = \"{}\"]`", name, value));
+        }
+        match forms.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+            _ => forms.join(""),
+        }
+    }
+}
+
+macro_rules! template {
+    (Word) => { template!(@ true, None, None) };
+    (List: $list: expr) => { template!(@ false, Some($list), None) };
+    (NameValueStr: $value: expr) => { template!(@ false, None, Some($value)) };
+    (Word, List: $list: expr) => { template!(@ true, Some($list), None) };
+    (Word, NameValueStr: $value: expr) => { template!(@ true, None, Some($value)) };
+    (@ $word: expr, $list: expr, $name_value_str: expr) => {
+        AttributeTemplate { word: $word, list: $list, name_value_str: $name_value_str }
+    };
+}
+
+/// A builtin attribute: the forms it is written in, what it may be applied
+/// to, and whether it may be written more than once.
+struct BuiltinAttr {
+    name: &'static str,
+    template: AttributeTemplate,
+    /// The error code for writing it in any other form, if it has one.
+    malformed_code: Option<&'static str>,
+    /// The targets the attribute may be applied to, or `None` if it is not
+    /// restricted here.
+    targets: Option<&'static [Target]>,
+    /// What the attribute should be applied to, as the error says it.
+    applies_to: &'static str,
+    /// The label on a target the attribute can't be applied to.
+    label: &'static str,
+    /// The error code for applying it to anything else, if it has one.
+    code: Option<&'static str>,
+    /// Whether the attribute simply has no effect on a target it can't be
+    /// applied to, so that applying it there is linted by
+    /// `misplaced_inert_attributes` rather than a hard error.
+    inert: bool,
+    /// Where the attribute may only be written once, if anywhere.
+    duplicates: Option<DuplicateScope>,
+}
+
+/// Every builtin attribute checked here. Writing one of them in a form its
+/// template does not accept is reported as "attribute must be of the form ...",
+/// applying it to a target it does not list as "attribute should be applied
+/// to ...".
+const BUILTIN_ATTRS: &[BuiltinAttr] = &[
+    BuiltinAttr {
+        name: "inline",
+        template: template!(Word, List: "always|never"),
+        malformed_code: None,
+        targets: Some(&[Target::Fn, Target::Closure]),
+        applies_to: "function or closure",
+        label: "not a function or closure",
+        code: Some("E0518"),
+        inert: false,
+        duplicates: Some(DuplicateScope::Item),
+    },
+    BuiltinAttr {
+        name: "cold",
+        template: template!(Word),
+        malformed_code: None,
+        targets: Some(&[Target::Fn, Target::ForeignFn, Target::Closure]),
+        applies_to: "a function or closure",
+        label: "not a function or closure",
+        code: Some("E0713"),
+        inert: true,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "must_use",
+        template: template!(Word, NameValueStr: "reason"),
+        malformed_code: None,
+        targets: Some(&[Target::Fn, Target::ForeignFn, Target::Struct, Target::Enum,
+                        Target::Union, Target::Trait]),
+        applies_to: "a function, struct, enum, union or trait",
+        label: "not a function, struct, enum, union or trait",
+        code: Some("E0718"),
+        inert: true,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "non_exhaustive",
+        template: template!(Word),
+        malformed_code: Some("E0702"),
+        targets: Some(&[Target::Struct, Target::Enum, Target::Variant]),
+        applies_to: "a struct, enum or enum variant",
+        label: "not a struct, enum or enum variant",
+        code: Some("E0701"),
+        inert: false,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "used",
+        template: template!(Word),
+        malformed_code: None,
+        targets: Some(&[Target::Static]),
+        applies_to: "a `static` variable",
+        label: "not a `static` variable",
+        code: Some("E0714"),
+        inert: false,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "target_feature",
+        template: template!(List: r#"enable = "name""#),
+        malformed_code: None,
+        targets: Some(&[Target::Fn]),
+        applies_to: "a function",
+        label: "not a function",
+        code: Some("E0715"),
+        inert: false,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "wasm_import_module",
+        template: template!(NameValueStr: "name"),
+        malformed_code: None,
+        targets: Some(&[Target::ForeignMod]),
+        applies_to: "an `extern` block",
+        label: "not an `extern` block",
+        code: Some("E0716"),
+        inert: false,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "rustc_args_required_const",
+        template: template!(List: "N, ..."),
+        malformed_code: None,
+        targets: Some(&[Target::Fn, Target::ForeignFn]),
+        applies_to: "a function",
+        label: "not a function",
+        code: None,
+        inert: false,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "wasm_custom_section",
+        template: template!(NameValueStr: "name"),
+        malformed_code: None,
+        targets: Some(&[Target::Const]),
+        applies_to: "a constant",
+        label: "not a constant",
+        code: None,
+        inert: false,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "no_mangle",
+        template: template!(Word),
+        malformed_code: None,
+        // `#[no_mangle] const` is linted by `no_mangle_const_items`, which
+        // suggests making it a static.
+        targets: Some(&[Target::Fn, Target::Static, Target::Const]),
+        applies_to: "a function or static",
+        label: "not a function or static",
+        code: Some("E0712"),
+        inert: true,
+        duplicates: Some(DuplicateScope::Item),
+    },
+    BuiltinAttr {
+        name: "export_name",
+        template: template!(NameValueStr: "name"),
+        malformed_code: Some("E0558"),
+        targets: Some(&[Target::Fn, Target::Static]),
+        applies_to: "a function or static",
+        label: "not a function or static",
+        code: Some("E0726"),
+        inert: true,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "link_name",
+        template: template!(NameValueStr: "name"),
+        malformed_code: None,
+        targets: Some(&[Target::ForeignFn, Target::ForeignStatic]),
+        applies_to: "a foreign function or static",
+        label: "not a foreign function or static",
+        code: Some("E0727"),
+        inert: true,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "link_section",
+        template: template!(NameValueStr: "name"),
+        malformed_code: None,
+        targets: Some(&[Target::Fn, Target::Static]),
+        applies_to: "a function or static",
+        label: "not a function or static",
+        code: Some("E0728"),
+        inert: true,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "thread_local",
+        template: template!(Word),
+        malformed_code: None,
+        targets: Some(&[Target::Static, Target::ForeignStatic]),
+        applies_to: "a static",
+        label: "not a static",
+        code: Some("E0729"),
+        inert: false,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "naked",
+        template: template!(Word),
+        malformed_code: None,
+        targets: Some(&[Target::Fn]),
+        applies_to: "a function",
+        label: "not a function",
+        code: Some("E0730"),
+        inert: false,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "track_caller",
+        template: template!(Word),
+        malformed_code: None,
+        targets: Some(&[Target::Fn]),
+        applies_to: "a function",
+        label: "not a function",
+        code: Some("E0732"),
+        inert: false,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "macro_export",
+        template: template!(Word, List: "local_inner_macros"),
+        malformed_code: None,
+        targets: Some(&[Target::MacroDef]),
+        applies_to: "a `macro_rules!` definition",
+        label: "not a `macro_rules!` definition",
+        code: Some("E0736"),
+        inert: true,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "path",
+        template: template!(NameValueStr: "file"),
+        malformed_code: None,
+        targets: Some(&[Target::Mod]),
+        applies_to: "a module",
+        label: "not a module",
+        code: Some("E0737"),
+        inert: true,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "no_link",
+        template: template!(Word),
+        malformed_code: None,
+        targets: Some(&[Target::ExternCrate]),
+        applies_to: "an `extern crate` item",
+        label: "not an `extern crate` item",
+        code: Some("E0738"),
+        inert: true,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "marker",
+        template: template!(Word),
+        malformed_code: None,
+        targets: Some(&[Target::Trait]),
+        applies_to: "a trait",
+        label: "not a trait",
+        code: Some("E0739"),
+        inert: false,
+        duplicates: None,
+    },
+    BuiltinAttr {
+        name: "global_allocator",
+        template: template!(Word),
+        malformed_code: None,
+        targets: Some(&[Target::Static]),
+        applies_to: "a static",
+        label: "not a static",
+        code: Some("E0741"),
+        inert: false,
+        duplicates: Some(DuplicateScope::Crate),
+    },
+];
+
+fn builtin_attr(attr: &hir::Attribute) -> Option<&'static BuiltinAttr> {
+    BUILTIN_ATTRS.iter().find(|builtin| attr.check_name(builtin.name))
 }
 
+/// Attributes that only make sense on particular items, with what they apply to,
+/// which are an error when written as crate attributes.
+const ITEM_ONLY_ATTRS: &[(&str, &str)] = &[
+    ("inline", "functions and closures"),
+    ("cold", "functions and closures"),
+    ("naked", "functions"),
+    ("track_caller", "functions"),
+    ("macro_export", "`macro_rules!` definitions"),
+    ("path", "modules"),
+    ("no_link", "`extern crate` items"),
+    ("marker", "traits"),
+    ("global_allocator", "statics"),
+    ("target_feature", "functions"),
+    ("thread_local", "statics"),
+    ("used", "statics"),
+    ("non_exhaustive", "structs and enums"),
+];
+
 struct CheckAttrVisitor<'a, 'tcx: 'a> {
     tcx: TyCtxt<'a, 'tcx, 'tcx>,
+    results: RefCell<Vec<AttrCheckResult>>,
+    /// Whether the crate is marked `#![no_builtins]`.
+    no_builtins: bool,
+    /// Every use of an attribute in `BUILTIN_ATTRS` with `DuplicateScope::Crate`,
+    /// checked once the whole crate has been visited.
+    crate_singletons: RefCell<Vec<(NodeId, hir::Attribute, Target)>>,
+    /// `#[used]` statics that are neither exported nor placed in a section,
+    /// with the span of their `#[used]`.
+    unexported_used_statics: RefCell<Vec<(NodeId, Span)>>,
+    /// Every static a path in a body refers to.
+    referenced_statics: RefCell<DefIdSet>,
 }
 
 impl<'a, 'tcx> CheckAttrVisitor<'a, 'tcx> {
+    fn new(tcx: TyCtxt<'a, 'tcx, 'tcx>) -> CheckAttrVisitor<'a, 'tcx> {
+        CheckAttrVisitor {
+            tcx,
+            results: RefCell::new(Vec::new()),
+            no_builtins: attr::contains_name(tcx.hir.krate_attrs(), "no_builtins"),
+            crate_singletons: RefCell::new(Vec::new()),
+            unexported_used_statics: RefCell::new(Vec::new()),
+            referenced_statics: RefCell::new(DefIdSet()),
+        }
+    }
+
+    /// Take in what checking a single module found, for the checks spanning the
+    /// whole crate. Its results are left in the module's `check_mod_attrs`.
+    fn add_item_checks(&self, checks: &ItemAttrChecks) {
+        self.crate_singletons.borrow_mut().extend(checks.crate_singletons.iter().cloned());
+        self.unexported_used_statics.borrow_mut()
+            .extend(checks.unexported_used_statics.iter().cloned());
+        self.referenced_statics.borrow_mut().extend(checks.referenced_statics.iter().cloned());
+    }
+
+    fn into_item_checks(self) -> ItemAttrChecks {
+        ItemAttrChecks {
+            results: self.results.into_inner(),
+            crate_singletons: self.crate_singletons.into_inner(),
+            unexported_used_statics: self.unexported_used_statics.into_inner(),
+            referenced_statics: self.referenced_statics.into_inner(),
+        }
+    }
+
+    /// Record that `attr`, attached to the node `id`, is not valid on `target`.
+    fn record(&self,
+              attr: &hir::Attribute,
+              id: NodeId,
+              target: Target,
+              outcome: AttrCheckOutcome) {
+        let def_id = match self.tcx.hir.opt_local_def_id(id) {
+            Some(def_id) => def_id,
+            None => self.tcx.hir.local_def_id(self.tcx.hir.get_parent(id)),
+        };
+        self.results.borrow_mut().push(AttrCheckResult {
+            attr_name: Symbol::intern(&attr.path.to_string()),
+            def_id,
+            target,
+            outcome,
+        });
+    }
+
     /// Check any attribute.
-    fn check_attributes(&self, item: &hir::Item, target: Target) {
-        if target == Target::Fn || target == Target::Const {
-            self.tcx.codegen_fn_attrs(self.tcx.hir.local_def_id(item.id));
-        } else if let Some(a) = item.attrs.iter().find(|a| a.check_name("target_feature")) {
-            self.tcx.sess.struct_span_err(a.span, "attribute should be applied to a function")
-                .span_label(item.span, "not a function")
-                .emit();
+    ///
+    /// Attributes belonging to a tool, like `#[rustfmt::skip]`, are only
+    /// checked against the targets a plugin registered for them: built-in
+    /// attributes are always matched on their whole path, so a scoped attribute
+    /// is never mistaken for the built-in sharing its last segment.
+    fn check_attributes(&self,
+                        id: NodeId,
+                        attrs: &[hir::Attribute],
+                        span: &Span,
+                        target: Target,
+                        item: Option<&hir::Item>,
+                        decl: Option<&hir::FnDecl>) {
+        // `fn_sig` is only defined for constants without `#[target_feature]`,
+        // which is reported below.
+        if target == Target::Fn ||
+           target == Target::Const && !attr::contains_name(attrs, "target_feature") {
+            self.tcx.codegen_fn_attrs(self.tcx.hir.local_def_id(id));
+        }
+
+        // Conflicting `#[inline]`s are reported as such, rather than as duplicates.
+        let inline_conflict = self.check_inline_conflict(id, attrs, target);
+        for builtin in BUILTIN_ATTRS {
+            if builtin.name == "inline" && inline_conflict {
+                continue;
+            }
+            if let Some(scope) = builtin.duplicates {
+                self.check_duplicates(id, attrs, builtin.name, target, scope);
+            }
         }
 
         let mut has_wasm_import_module = false;
-        for attr in &item.attrs {
+        for attr in attrs {
+            if is_derive_generated(attr.span) {
+                continue;
+            }
+            if attr.check_name("wasm_import_module") {
+                has_wasm_import_module = true;
+            }
+            if attr.is_scoped() {
+                self.check_tool_attr_target(id, attr, span, target);
+                continue;
+            }
+            if !self.check_target(id, attr, span, target) {
+                // A misplaced `#[export_name]` has no effect, but a missing
+                // or null-containing name is still an error.
+                if attr.check_name("export_name") && self.check_template(id, attr, target) {
+                    self.check_null_characters(attr);
+                }
+                continue;
+            }
+            if !self.check_template(id, attr, target) {
+                continue;
+            }
             if attr.check_name("inline") {
-                self.check_inline(attr, &item.span, target)
+                self.check_inline(id, attr, target)
             } else if attr.check_name("non_exhaustive") {
-                self.check_non_exhaustive(attr, item, target)
-            } else if attr.check_name("wasm_import_module") {
-                has_wasm_import_module = true;
-                if attr.value_str().is_none() {
-                    self.tcx.sess.span_err(attr.span, "\
-                        must be of the form #[wasm_import_module = \"...\"]");
+                self.check_non_exhaustive(id, attr, span, target)
+            } else if attr.check_name("export_name") || attr.check_name("link_name") ||
+                      attr.check_name("link_section") {
+                self.check_symbol_name(id, attr);
+            } else if attr.check_name("link") {
+                if let Some(&hir::Item { node: hir::ItemForeignMod(ref fm), .. }) = item {
+                    self.check_link(attr, fm);
                 }
-                if target != Target::ForeignMod {
-                    self.tcx.sess.span_err(attr.span, "\
-                        must only be attached to foreign modules");
+            } else if attr.check_name("start") {
+                if let Some(decl) = decl {
+                    self.check_start(id, attr, span, target, decl);
                 }
-            } else if attr.check_name("wasm_custom_section") {
-                if target != Target::Const {
-                    self.tcx.sess.span_err(attr.span, "only allowed on consts");
+            } else if attr.check_name("rustc_args_required_const") {
+                self.check_rustc_args_required_const(id, attr, target);
+            } else if attr.check_name("naked") {
+                self.check_naked(id, attr, span);
+            } else if attr.check_name("track_caller") {
+                self.check_track_caller(id, attr, span);
+            } else if attr.check_name("macro_export") {
+                self.check_macro_export(id, attr);
+            } else if attr.check_name("thread_local") {
+                self.check_thread_local(id, attr, target);
+            } else if attr.check_name("global_allocator") {
+                if let Some(item) = item {
+                    self.check_global_allocator(id, attr, item);
                 }
             }
         }
@@ -93,72 +685,1045 @@ impl<'a, 'tcx> CheckAttrVisitor<'a, 'tcx> {
             self.tcx.sess.target.target.arch == "wasm32" &&
             false // FIXME: eventually enable this warning when stable
         {
-            self.tcx.sess.span_warn(item.span, "\
+            self.tcx.sess.span_warn(*span, "\
                 must have a #[wasm_import_module = \"...\"] attribute, this \
                 will become a hard error before too long");
         }
 
-        self.check_repr(item, target);
-        self.check_used(item, target);
+        self.check_repr(id, attrs, span, target, item);
+        self.check_non_exhaustive_repr(id, attrs, target);
+        self.check_used(id, attrs, target);
+        if let Some(item) = item {
+            self.check_no_mangle_static_mut(id, attrs, item);
+        }
+        if let Some(&hir::Item { node: hir::ItemFn(.., ref generics, _), .. }) = item {
+            self.check_no_mangle_generics(id, attrs, *span, &[generics]);
+        }
+        if target == Target::Fn {
+            self.check_codegen_fn_attr_conflicts(id, attrs);
+        }
+        if let Some(&hir::Item { node: hir::ItemImpl(.., ref self_ty, _), .. }) = item {
+            self.check_packed_derive(attrs, *span, self_ty);
+        }
     }
 
-    /// Check if an `#[inline]` is applied to a function or a closure.
-    fn check_inline(&self, attr: &hir::Attribute, span: &Span, target: Target) {
-        if target != Target::Fn && target != Target::Closure {
-            struct_span_err!(self.tcx.sess,
-                             attr.span,
-                             E0518,
-                             "attribute should be applied to function or closure")
-                .span_label(*span, "not a function or closure")
+    /// Check that a `#[global_allocator]` static spells out its type, and that the
+    /// type implements `GlobalAlloc`. The shims generated for the allocator call
+    /// it through that trait, so an error there would point into the expansion
+    /// instead; trait error reporting leaves those to this check.
+    fn check_global_allocator(&self, id: NodeId, attr: &hir::Attribute, item: &hir::Item) {
+        let ty = match item.node {
+            hir::ItemStatic(ref ty, ..) => ty,
+            _ => return,
+        };
+        if let hir::TyInfer = ty.node {
+            self.tcx.sess.struct_span_err(ty.span,
+                                          "`#[global_allocator]` static must have an \
+                                           explicit type")
+                .span_label(attr.span, "the global allocator is declared here")
+                .help("write out the type of the allocator, which must implement \
+                       `GlobalAlloc`")
+                .emit();
+            self.record(attr, id, Target::Static, AttrCheckOutcome::Error);
+            return;
+        }
+
+        let global_alloc = match self.tcx.lang_items().global_alloc_trait() {
+            Some(def_id) => def_id,
+            None => return,
+        };
+        let def_id = self.tcx.hir.local_def_id(id);
+        let static_ty = self.tcx.type_of(def_id);
+        if static_ty.references_error() {
+            return;
+        }
+        let param_env = self.tcx.param_env(def_id);
+        let is_allocator = self.tcx.infer_ctxt().enter(|infcx| {
+            traits::type_known_to_meet_bound(&infcx, param_env, static_ty, global_alloc, ty.span)
+        });
+        if !is_allocator {
+            let trait_path = self.tcx.item_path_str(global_alloc);
+            struct_span_err!(self.tcx.sess, ty.span, E0277,
+                             "the trait bound `{}: {}` is not satisfied", static_ty, trait_path)
+                .span_label(ty.span, format!("the trait `{}` is not implemented for `{}`",
+                                             trait_path, static_ty))
+                .span_label(attr.span, "the global allocator is declared here")
                 .emit();
+            self.record(attr, id, Target::Static, AttrCheckOutcome::Error);
         }
     }
 
-    /// Check if the `#[non_exhaustive]` attribute on an `item` is valid.
-    fn check_non_exhaustive(&self, attr: &hir::Attribute, item: &hir::Item, target: Target) {
-        match target {
-            Target::Struct | Target::Enum => { /* Valid */ },
-            _ => {
-                struct_span_err!(self.tcx.sess,
-                                 attr.span,
-                                 E0701,
-                                 "attribute can only be applied to a struct or enum")
-                    .span_label(item.span, "not a struct or enum")
+    /// Check the attributes of the macros the crate exports, which are not items.
+    /// Other `macro_rules!` macros are not kept in the HIR, but without a
+    /// `#[macro_export]` there is nothing on them to check. A `macro` item is
+    /// exported with `pub` instead, so it is not a target for `#[macro_export]`.
+    fn check_exported_macros(&self) {
+        for macro_def in &self.tcx.hir.krate().exported_macros {
+            let target = if macro_def.legacy { Target::MacroDef } else { Target::Other };
+            self.check_attributes(macro_def.id, &macro_def.attrs, &macro_def.span, target,
+                                  None, None);
+        }
+    }
+
+    /// Check the attributes of the crate root itself, which the item visitor never
+    /// sees. Crate-level-only attributes written on items are already reported
+    /// by the `unused_attributes` lint.
+    fn check_crate_attributes(&self) {
+        for attr in &self.tcx.hir.krate().attrs {
+            let applies_to = ITEM_ONLY_ATTRS.iter().find(|&&(name, _)| attr.check_name(name));
+            if let Some(&(name, applies_to)) = applies_to {
+                struct_span_err!(self.tcx.sess, attr.span, E0720,
+                                 "`#![{}]` cannot be applied to a crate", name)
+                    .help(&format!("`#[{}]` only applies to {}; write it as an outer \
+                                    attribute on the item it is meant for",
+                                   name, applies_to))
                     .emit();
-                return;
+                self.record(attr, CRATE_NODE_ID, Target::Crate, AttrCheckOutcome::Error);
+            }
+        }
+    }
+
+    /// Report a `#![no_std]` binary without a `#[panic_implementation]`, either of
+    /// its own or from a crate it links, at its `#![no_std]`.
+    fn check_no_std_panic_implementation(&self) {
+        if !weak_lang_items::is_no_std_executable(self.tcx) ||
+           self.tcx.lang_items().panic_impl().is_some() {
+            return;
+        }
+        let no_std = self.tcx.hir.krate().attrs.iter().find(|attr| attr.check_name("no_std"));
+        if let Some(no_std) = no_std {
+            self.tcx.sess.struct_span_err(no_std.span,
+                                          "`#[panic_implementation]` function required, \
+                                           but not found")
+                .span_label(no_std.span, "`std` is not linked, so it can't provide one")
+                .help("define a function taking `&PanicInfo` and returning `!` marked \
+                       `#[panic_implementation]`, or link a crate that defines one")
+                .emit();
+        }
+    }
+
+    /// Warn when a derive takes references to the fields of a `#[repr(packed)]`
+    /// struct, which may be unaligned. The `#[derive]` itself is gone by now, so
+    /// this looks at the `#[automatically_derived]` impls it generated, and
+    /// reports at the derive they came from. Structs that are `Copy` and not
+    /// generic are fine: their derives copy the fields out first.
+    fn check_packed_derive(&self,
+                           impl_attrs: &[hir::Attribute],
+                           impl_span: Span,
+                           self_ty: &hir::Ty) {
+        if !attr::contains_name(impl_attrs, "automatically_derived") {
+            return;
+        }
+        let (derive, derive_span) = match impl_span.ctxt().outer().expn_info() {
+            Some(ExpnInfo { format: ExpnFormat::MacroAttribute(name), call_site, .. }) => {
+                (name.as_str(), call_site)
+            }
+            _ => return,
+        };
+        let trait_name = match derive.trim_left_matches("derive(").trim_right_matches(')') {
+            name @ "Clone" | name @ "Debug" | name @ "Hash" | name @ "PartialEq" |
+            name @ "PartialOrd" | name @ "Ord" if derive.starts_with("derive(") => name,
+            _ => return,
+        };
+        let def_id = match self_ty.node {
+            hir::TyPath(hir::QPath::Resolved(None, ref path)) => match path.def {
+                Def::Struct(def_id) => def_id,
+                _ => return,
+            },
+            _ => return,
+        };
+        let struct_id = match self.tcx.hir.as_local_node_id(def_id) {
+            Some(struct_id) => struct_id,
+            None => return,
+        };
+        let item = self.tcx.hir.expect_item(struct_id);
+        let has_type_params = item.node.generics().map_or(false, |generics| {
+            generics.params.iter().any(|param| match param.kind {
+                hir::GenericParamKind::Type { .. } => true,
+                _ => false,
+            })
+        });
+        if attr::contains_name(&item.attrs, "rustc_copy_clone_marker") && !has_type_params {
+            return;
+        }
+        let packed_span = item.attrs.iter()
+            .filter(|attr| attr.path == "repr")
+            .filter_map(|attr| attr.meta_item_list())
+            .flat_map(|hints| hints)
+            .find(|hint| hint.check_name("packed"))
+            .map(|hint| hint.span);
+        if let Some(packed_span) = packed_span {
+            self.tcx.struct_span_lint_node(PACKED_DERIVE_REFERENCES,
+                                           struct_id,
+                                           derive_span,
+                                           &format!("`#[derive({})]` takes references to the \
+                                                     fields of a packed struct",
+                                                    trait_name))
+                .span_label(packed_span, "the struct is packed here")
+                .note("the fields of a packed struct may be unaligned, and references to \
+                       them are undefined behavior")
+                .help("derive `Copy` as well, so the fields are copied out first, or \
+                       implement the trait by hand")
+                .emit();
+        }
+    }
+
+    /// Check the codegen attributes of a function against each other. Conflicts
+    /// that make an attribute impossible to honour are reported together in a
+    /// single error, merely questionable combinations are linted.
+    fn check_codegen_fn_attr_conflicts(&self, id: NodeId, attrs: &[hir::Attribute]) {
+        let find = |name: &str| attrs.iter().rev().find(|attr| {
+            attr.check_name(name) && !is_derive_generated(attr.span)
+        });
+        let inline = self.tcx.codegen_fn_attrs(self.tcx.hir.local_def_id(id)).inline;
+        let inline_attr = match inline {
+            InlineAttr::Hint | InlineAttr::Always => find("inline"),
+            InlineAttr::None | InlineAttr::Never => None,
+        };
+
+        let mut conflicts = vec![];
+        if let (Some(inline_attr), Some(naked)) = (inline_attr, find("naked")) {
+            conflicts.push((inline_attr.span, naked.span,
+                            "`#[inline]` conflicts with `#[naked]`, as a naked function \
+                             cannot be inlined into its callers"));
+        }
+        let track_caller = find("track_caller");
+        if let (Some(track_caller), Some(naked)) = (track_caller, find("naked")) {
+            conflicts.push((track_caller.span, naked.span,
+                            "`#[track_caller]` conflicts with `#[naked]`, as a naked function \
+                             cannot receive the location of its caller"));
+        }
+        let mut always_with_features = None;
+        if inline == InlineAttr::Always {
+            if let (Some(inline_attr), Some(features)) = (inline_attr, find("target_feature")) {
+                conflicts.push((inline_attr.span, features.span,
+                                "`#[inline(always)]` conflicts with `#[target_feature]`, as \
+                                 the function may only be inlined into callers that enable \
+                                 the same features"));
+                always_with_features = Some(inline_attr.span);
+            }
+        }
+
+        if !conflicts.is_empty() {
+            let mut spans = vec![];
+            for &(first, second, _) in &conflicts {
+                for &span in &[first, second] {
+                    if !spans.contains(&span) {
+                        spans.push(span);
+                    }
+                }
+            }
+            let mut err = struct_span_err!(self.tcx.sess, spans, E0719,
+                                           "conflicting codegen attributes on this function");
+            for &(_, _, note) in &conflicts {
+                err.note(note);
+            }
+            if let Some(span) = always_with_features {
+                err.span_suggestions_with_applicability(
+                    span,
+                    "leave the decision to inline it to the compiler, or never inline it",
+                    vec!["#[inline]".to_string(), "#[inline(never)]".to_string()],
+                    Applicability::MaybeIncorrect,
+                );
+            }
+            err.emit();
+            if let Some(inline_attr) = inline_attr {
+                self.record(inline_attr, id, Target::Fn, AttrCheckOutcome::Error);
+            }
+            if let Some(track_caller) = track_caller {
+                self.record(track_caller, id, Target::Fn, AttrCheckOutcome::Error);
+            }
+        }
+
+        self.check_inline_cold(id, attrs);
+        self.check_inline_always_exported(id, attrs, inline);
+    }
+
+    /// Lint on an `#[inline(always)]` function that is also exported under a
+    /// symbol name: callers in other crates go through the symbol, so the request
+    /// to always inline can't be honoured for them.
+    fn check_inline_always_exported(&self,
+                                     id: NodeId,
+                                     attrs: &[hir::Attribute],
+                                     inline: InlineAttr) {
+        if inline != InlineAttr::Always {
+            return;
+        }
+        let inline = attrs.iter().find(|attr| {
+            attr.check_name("inline") && !is_derive_generated(attr.span)
+        });
+        let export = attrs.iter().find(|attr| {
+            attr.check_name("no_mangle") || attr.check_name("export_name")
+        });
+        if let (Some(inline), Some(export)) = (inline, export) {
+            let export_name = if export.check_name("no_mangle") {
+                "#[no_mangle]"
+            } else {
+                "#[export_name]"
+            };
+            self.tcx.struct_span_lint_node(INLINE_ALWAYS_EXPORTED,
+                                           id,
+                                           vec![inline.span, export.span],
+                                           &format!("`#[inline(always)]` function is exported \
+                                                     with `{}`", export_name))
+                .note("calls through the exported symbol can't be inlined")
+                .span_suggestion_with_applicability(inline.span,
+                                                    "remove `#[inline(always)]`",
+                                                    String::new(),
+                                                    Applicability::MaybeIncorrect)
+                .span_suggestion_with_applicability(export.span,
+                                                    &format!("remove `{}`", export_name),
+                                                    String::new(),
+                                                    Applicability::MaybeIncorrect)
+                .emit();
+            self.record(inline, id, Target::Fn, AttrCheckOutcome::Warning);
+        }
+    }
+
+    /// Warn on functions which are both `#[cold]` and `#[inline]` or
+    /// `#[inline(always)]`: a cold function is not supposed to be inlined into
+    /// its callers. `#[inline(never)]` agrees with `#[cold]` and is accepted.
+    fn check_inline_cold(&self, id: NodeId, attrs: &[hir::Attribute]) {
+        let cold = match attrs.iter().find(|attr| attr.check_name("cold")) {
+            Some(cold) => cold,
+            None => return,
+        };
+        let inline = attrs.iter().find(|attr| {
+            attr.check_name("inline") && !is_derive_generated(attr.span) &&
+                !attr::list_contains_name(&attr.meta_item_list().unwrap_or_default(), "never")
+        });
+        if let Some(inline) = inline {
+            self.tcx.struct_span_lint_node(INLINE_AND_COLD,
+                                           id,
+                                           vec![inline.span, cold.span],
+                                           "function is marked both `#[inline]` and `#[cold]`")
+                .span_label(inline.span, "asks for the function to be inlined")
+                .span_label(cold.span, "asks for calls to the function to be kept out of hot paths")
+                .help("use `#[inline(never)]` if the function should not be inlined")
+                .emit();
+            self.record(inline, id, Target::Fn, AttrCheckOutcome::Warning);
+        }
+    }
+
+    /// Check that the attribute `name` appears at most once in `scope`. Copies on
+    /// a single item are reported right away, uses throughout the crate once
+    /// `check_crate_duplicates` runs.
+    fn check_duplicates(&self,
+                        id: NodeId,
+                        attrs: &[hir::Attribute],
+                        name: &str,
+                        target: Target,
+                        scope: DuplicateScope) {
+        let uses = attrs.iter().filter(|attr| attr.path == name && !is_derive_generated(attr.span));
+        match scope {
+            DuplicateScope::Item => {
+                let uses: Vec<_> = uses.map(|attr| (id, attr, target)).collect();
+                self.report_duplicates(name, scope, &uses);
+            }
+            DuplicateScope::Crate => {
+                self.crate_singletons.borrow_mut()
+                    .extend(uses.map(|attr| (id, attr.clone(), target)));
+            }
+        }
+    }
+
+    fn check_crate_duplicates(&self) {
+        let singletons = self.crate_singletons.borrow();
+        for builtin in BUILTIN_ATTRS {
+            if builtin.duplicates != Some(DuplicateScope::Crate) {
+                continue;
+            }
+            let uses: Vec<_> = singletons.iter()
+                .filter(|&&(_, ref attr, _)| attr.path == builtin.name)
+                .map(|&(id, ref attr, target)| (id, attr, target))
+                .collect();
+            self.report_duplicates(builtin.name, DuplicateScope::Crate, &uses);
+        }
+    }
+
+    /// Report every use of `name` after the first, labelling all of them.
+    fn report_duplicates(&self,
+                         name: &str,
+                         scope: DuplicateScope,
+                         uses: &[(NodeId, &hir::Attribute, Target)]) {
+        if uses.len() < 2 {
+            return;
+        }
+        let mut spans = MultiSpan::from_spans(uses[1..].iter()
+            .map(|&(_, attr, _)| attr.span)
+            .collect());
+        let (first, again) = match scope {
+            DuplicateScope::Item => ("first specified here", "specified again here"),
+            DuplicateScope::Crate => ("first defined here", "defined again here"),
+        };
+        spans.push_span_label(uses[0].1.span, first.to_string());
+        for &(_, attr, _) in &uses[1..] {
+            spans.push_span_label(attr.span, again.to_string());
+        }
+        let outcome = match scope {
+            DuplicateScope::Item => {
+                self.tcx.sess.span_warn(spans,
+                                        &format!("`#[{}]` is specified more than once", name));
+                AttrCheckOutcome::Warning
+            }
+            DuplicateScope::Crate => {
+                self.tcx.sess.span_err(spans,
+                                       &format!("cannot define more than one `#[{}]`", name));
+                AttrCheckOutcome::Error
+            }
+        };
+        for &(id, attr, target) in &uses[1..] {
+            self.record(attr, id, target, outcome);
+        }
+    }
+
+    /// Check that `attr`, if it is one of `BUILTIN_ATTRS`, is applied to a
+    /// target it lists. Returns `false` if it was reported.
+    fn check_target(&self, id: NodeId, attr: &hir::Attribute, span: &Span, target: Target)
+                    -> bool {
+        let builtin = match builtin_attr(attr) {
+            Some(builtin) => builtin,
+            None => return true,
+        };
+        match builtin.targets {
+            Some(targets) if !targets.contains(&target) => {}
+            _ => return true,
+        }
+
+        let msg = format!("attribute should be applied to {}", builtin.applies_to);
+        let mut err = if builtin.inert {
+            let mut err = self.tcx.struct_span_lint_node(MISPLACED_INERT_ATTRIBUTES,
+                                                         id,
+                                                         attr.span,
+                                                         &msg);
+            if let Some(code) = builtin.code {
+                err.code(DiagnosticId::Error(code.to_owned()));
+            }
+            err
+        } else {
+            self.struct_builtin_err(attr.span, &msg, builtin.code)
+        };
+        if target == Target::ForeignFn {
+            err.span_label(*span, "a foreign function, whose body is not compiled in this crate");
+        } else if target == Target::Closure && builtin.name == "no_mangle" {
+            err.span_label(*span, "closures have no name to export them under");
+        } else {
+            err.span_label(*span, builtin.label);
+        }
+        if target == Target::ForeignStatic && builtin.name == "used" {
+            err.note("a foreign static is defined in another crate or object file, which is \
+                      where it has to be kept");
+        }
+        if target == Target::TyAlias && builtin.name == "must_use" && self.is_associated_item(id) {
+            err.help("put the attribute on the definition of the type, or on the trait");
+        }
+        // `#[link_name]` names the symbol an item in an `extern` block is imported
+        // from. The attribute renaming a definition is `#[export_name]`.
+        if builtin.name == "link_name" && (target == Target::Fn || target == Target::Static) {
+            err.note("`#[link_name]` only applies to items in `extern` blocks");
+            err.span_suggestion_with_applicability(attr.path.span,
+                                                   "use `#[export_name]` to export the item \
+                                                    under this name",
+                                                   "export_name".to_owned(),
+                                                   Applicability::MaybeIncorrect);
+        } else {
+            suggest_removal(&mut err, attr);
+        }
+        if builtin.name == "thread_local" {
+            err.note("`#[thread_local]` is unstable and only gives statics thread-local \
+                      storage, the `thread_local!` macro declares thread-local values on \
+                      stable Rust");
+        }
+        if builtin.name == "path" {
+            err.note("`#[path]` sets the file a `mod` declaration loads the module from");
+        }
+        if builtin.name == "target_feature" && target == Target::Closure {
+            err.help("closures can't be `unsafe`; move the body into an `unsafe fn` with the \
+                      attribute, and call that in an `unsafe` block");
+        }
+        if builtin.name == "inline" {
+            if target == Target::Local && self.binds_closure(id) {
+                err.help("to inline the closure, put the attribute on the closure expression");
+            }
+            if target == Target::Expression {
+                if let Some(kind) = self.initializer_owner(id) {
+                    err.note(&format!("the initializer of {} is evaluated at compile time, \
+                                       so there is no call to inline", kind));
+                }
+            }
+        }
+        err.emit();
+        let outcome = if builtin.inert {
+            match self.tcx.lint_level_at_node(MISPLACED_INERT_ATTRIBUTES, id).0 {
+                lint::Allow => AttrCheckOutcome::Allowed,
+                lint::Warn => AttrCheckOutcome::Warning,
+                lint::Deny | lint::Forbid => AttrCheckOutcome::Error,
+            }
+        } else {
+            AttrCheckOutcome::Error
+        };
+        self.record(attr, id, target, outcome);
+        false
+    }
+
+    /// Check that an attribute of a tool is applied to one of the targets a
+    /// plugin registered for it. An attribute nothing was registered for is
+    /// left alone.
+    fn check_tool_attr_target(&self,
+                              id: NodeId,
+                              attr: &hir::Attribute,
+                              span: &Span,
+                              target: Target) {
+        let path = attr.path.to_string();
+        let registered = self.tcx.sess.plugin_attribute_targets.borrow();
+        let rule = match registered.iter().find(|rule| rule.path == path) {
+            Some(rule) => rule,
+            None => return,
+        };
+        if rule.targets.contains(&target) {
+            return;
+        }
+        let mut err = struct_span_err!(self.tcx.sess,
+                                       attr.span,
+                                       E0742,
+                                       "attribute should be applied to {}",
+                                       rule.applies_to);
+        err.span_label(*span, format!("not {}", rule.applies_to));
+        suggest_removal(&mut err, attr);
+        err.emit();
+        self.record(attr, id, target, AttrCheckOutcome::Error);
+    }
+
+    /// Check that `attr`, if it is one of `BUILTIN_ATTRS`, is written in a form
+    /// its template accepts. Returns `false` if an error was reported.
+    fn check_template(&self, id: NodeId, attr: &hir::Attribute, target: Target) -> bool {
+        let builtin = match builtin_attr(attr) {
+            Some(builtin) => builtin,
+            None => return true,
+        };
+        let template = builtin.template;
+        if template.matches(attr) {
+            return true;
+        }
+
+        let mut err = self.struct_builtin_err(
+            attr.span,
+            &format!("attribute must be of the form {}", template.describe(builtin.name)),
+            builtin.malformed_code,
+        );
+        // Remove everything after the name of an attribute that takes nothing.
+        if template.word && template.list.is_none() && template.name_value_str.is_none() {
+            if let Some(last) = attr.tokens.trees().last() {
+                err.span_suggestion_with_applicability(
+                    attr.path.span.shrink_to_hi().to(last.span()),
+                    "remove the arguments",
+                    String::new(),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+        err.emit();
+        self.record(attr, id, target, AttrCheckOutcome::Error);
+        false
+    }
+
+    fn struct_builtin_err(&self, span: Span, msg: &str, code: Option<&str>)
+                          -> DiagnosticBuilder<'tcx> {
+        match code {
+            Some(code) => self.tcx.sess.struct_span_err_with_code(
+                span,
+                msg,
+                DiagnosticId::Error(code.to_owned()),
+            ),
+            None => self.tcx.sess.struct_span_err(span, msg),
+        }
+    }
+
+    /// Check an `#[inline]` on a function or closure.
+    fn check_inline(&self, id: NodeId, attr: &hir::Attribute, target: Target) {
+        if self.no_builtins && target == Target::Fn &&
+           attr::list_contains_name(&attr.meta_item_list().unwrap_or_default(), "always") {
+            self.tcx.struct_span_lint_node(INLINE_ALWAYS_IN_NO_BUILTINS,
+                                           id,
+                                           attr.span,
+                                           "`#[inline(always)]` function in a \
+                                            `#![no_builtins]` crate")
+                .note("the function is codegened in the crates calling it, where calls to \
+                       builtins such as `memcpy` may be introduced into its body")
+                .emit();
+            self.record(attr, id, target, AttrCheckOutcome::Warning);
+        }
+    }
+
+    /// Whether the `let` statement `id` is initialized with a closure.
+    fn binds_closure(&self, id: NodeId) -> bool {
+        if let Some(hir::map::NodeStmt(stmt)) = self.tcx.hir.find(id) {
+            if let hir::StmtDecl(ref decl, _) = stmt.node {
+                if let hir::DeclLocal(ref local) = decl.node {
+                    if let Some(&hir::ExprClosure(..)) = local.init.as_ref().map(|e| &e.node) {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    /// If the expression `id` is the whole initializer of a `const`, `static`
+    /// or associated `const`, returns the kind of that item. Blocks and branching
+    /// expressions are not considered initializers here, attributes on them
+    /// get the same diagnostics as on any other expression.
+    fn initializer_owner(&self, id: NodeId) -> Option<&'static str> {
+        match self.tcx.hir.expect_expr(id).node {
+            hir::ExprBlock(..) | hir::ExprIf(..) | hir::ExprMatch(..) => return None,
+            _ => {}
+        }
+        match self.tcx.hir.find(self.tcx.hir.get_parent_node(id)) {
+            Some(hir::map::NodeItem(item)) => match item.node {
+                hir::ItemConst(..) => Some("a `const`"),
+                hir::ItemStatic(..) => Some("a `static`"),
+                _ => None,
+            },
+            Some(hir::map::NodeImplItem(&hir::ImplItem {
+                node: hir::ImplItemKind::Const(..), ..
+            })) |
+            Some(hir::map::NodeTraitItem(&hir::TraitItem {
+                node: hir::TraitItemKind::Const(..), ..
+            })) => Some("an associated `const`"),
+            _ => None,
+        }
+    }
+
+    /// Check a `#[non_exhaustive]` on a struct, enum or variant.
+    fn check_non_exhaustive(&self,
+                            id: NodeId,
+                            attr: &hir::Attribute,
+                            span: &Span,
+                            target: Target) {
+        if target == Target::Struct {
+            let item = self.tcx.hir.expect_item(id);
+            if let hir::ItemStruct(hir::VariantData::Unit(_), _) = item.node {
+                self.tcx.struct_span_lint_node(NON_EXHAUSTIVE_UNIT_STRUCTS,
+                                               id,
+                                               attr.span,
+                                               "unit struct has no fields to mark as \
+                                                non-exhaustive")
+                    .span_label(*span, "struct has no fields")
+                    .help("use a struct with named fields if fields may be added later")
+                    .emit();
+                self.record(attr, id, target, AttrCheckOutcome::Warning);
+            }
+        }
+    }
+
+    /// Check the arguments of a `#[macro_export(..)]`, of which `local_inner_macros`
+    /// is the only one.
+    fn check_macro_export(&self, id: NodeId, attr: &hir::Attribute) {
+        for item in attr.meta_item_list().unwrap_or_default() {
+            if item.check_name("local_inner_macros") && item.word().is_some() {
+                continue;
+            }
+            self.tcx.sess.struct_span_err(item.span, "unknown argument for `#[macro_export]`")
+                .span_label(item.span, "expected `local_inner_macros`")
+                .emit();
+            self.record(attr, id, Target::MacroDef, AttrCheckOutcome::Error);
+        }
+    }
+
+    /// Check the form of a `#[link(name = "...", kind = "...", cfg(..))]` attribute
+    /// on an `extern` block.
+    fn check_link(&self, attr: &hir::Attribute, foreign_mod: &hir::ForeignMod) {
+        // Blocks of Rust and intrinsic functions don't link against anything.
+        match foreign_mod.abi {
+            Abi::Rust | Abi::RustIntrinsic | Abi::PlatformIntrinsic => return,
+            _ => {}
+        }
+        let items = match attr.meta_item_list() {
+            Some(items) => items,
+            None => return,
+        };
+
+        for item in &items {
+            if !LINK_KEYS.iter().any(|key| item.check_name(key)) {
+                self.tcx.sess.struct_span_err(item.span, "unknown argument for `#[link]`")
+                    .span_label(item.span, "expected one of `name`, `kind` or `cfg`")
+                    .emit();
+            }
+        }
+
+        let kind = items.iter().find(|item| item.check_name("kind"));
+        if let Some(kind) = kind.and_then(|kind| kind.meta_item()) {
+            if let Some(value) = kind.value_str() {
+                if !LINK_KINDS.contains(&&*value.as_str()) {
+                    let mut err = struct_span_err!(self.tcx.sess, attr.span, E0458,
+                                                   "unknown kind: `{}`", value);
+                    err.span_label(attr.span, "unknown kind");
+                    let kinds: Vec<_> = LINK_KINDS.iter()
+                        .map(|&kind| Symbol::intern(kind))
+                        .collect();
+                    let similar = find_best_match_for_name(kinds.iter(), &value.as_str(), None);
+                    if let (Some(similar), ast::MetaItemKind::NameValue(ref lit)) =
+                        (similar, &kind.node)
+                    {
+                        err.span_suggestion_with_applicability(
+                            lit.span,
+                            "a kind with a similar name exists",
+                            format!("\"{}\"", similar),
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                    err.emit();
+                }
+            }
+        }
+
+        if !items.iter().any(|item| item.check_name("name") && item.value_str().is_some()) {
+            struct_span_err!(self.tcx.sess, attr.span, E0459,
+                             "#[link(...)] specified without `name = \"foo\"`")
+                .span_label(attr.span, "missing `name` argument")
+                .emit();
+        }
+    }
+
+    /// Check that a `#[start]` function takes the arguments passed to it. Their
+    /// types are checked against the expected signature during type checking.
+    fn check_start(&self,
+                   id: NodeId,
+                   attr: &hir::Attribute,
+                   span: &Span,
+                   target: Target,
+                   decl: &hir::FnDecl) {
+        let args = decl.inputs.len();
+        if args != 2 {
+            self.tcx.sess.struct_span_err(*span, &format!("start function takes {} argument{} \
+                                                           but 2 are required",
+                                                          args,
+                                                          if args == 1 { "" } else { "s" }))
+                .note("expected signature `fn(isize, *const *const u8) -> isize`")
+                .emit();
+            self.record(attr, id, target, AttrCheckOutcome::Error);
+        }
+    }
+
+    /// Check that `#[rustc_args_required_const(..)]` only lists indices of the
+    /// arguments of the function it is applied to.
+    fn check_rustc_args_required_const(&self,
+                                       id: NodeId,
+                                       attr: &hir::Attribute,
+                                       target: Target) {
+        let list = match attr.meta_item_list() {
+            Some(list) => list,
+            None => return,
+        };
+
+        let arg_count = self.tcx.hir.fn_decl(id).map_or(0, |decl| decl.inputs.len());
+        let mut invalid_args = vec![];
+        for meta in &list {
+            match meta.literal().map(|lit| &lit.node) {
+                Some(&ast::LitKind::Int(index, _)) => {
+                    if index >= arg_count as u128 {
+                        let plural = if arg_count == 1 { "" } else { "s" };
+                        self.tcx.sess.struct_span_err(meta.span,
+                                                      "index exceeds number of arguments")
+                            .span_label(meta.span, format!("the function has {} argument{}",
+                                                           arg_count, plural))
+                            .emit();
+                        self.record(attr, id, target, AttrCheckOutcome::Error);
+                    }
+                }
+                _ => invalid_args.push(meta.span),
+            }
+        }
+        if !invalid_args.is_empty() {
+            self.tcx.sess.span_err(invalid_args, "arguments should be non-negative integers");
+            self.record(attr, id, target, AttrCheckOutcome::Error);
+        }
+    }
+
+    /// Warn on `#[non_exhaustive]` types with a `C` or primitive representation.
+    /// Their layout is meant to be relied on across an FFI boundary, yet other
+    /// crates can't construct them and the layout changes whenever a field or
+    /// variant is added.
+    fn check_non_exhaustive_repr(&self, id: NodeId, attrs: &[hir::Attribute], target: Target) {
+        if target != Target::Struct && target != Target::Enum {
+            return;
+        }
+        let non_exhaustive = match attrs.iter().find(|attr| attr.check_name("non_exhaustive")) {
+            Some(attr) => attr,
+            None => return,
+        };
+        let stable_hint = attrs.iter()
+            .filter(|attr| attr.path == "repr")
+            .filter_map(|attr| attr.meta_item_list())
+            .flat_map(|hints| hints)
+            .find(|hint| {
+                hint.name().map_or(false, |name| name == "C" || is_int_repr_hint(&name.as_str()))
+            });
+        if let Some(hint) = stable_hint {
+            self.tcx.struct_span_lint_node(NON_EXHAUSTIVE_STABLE_REPR,
+                                           id,
+                                           non_exhaustive.span,
+                                           "`#[non_exhaustive]` type has a stable representation")
+                .span_label(hint.span, "representation meant to be relied upon")
+                .note("other crates cannot construct this type, and adding fields or \
+                       variants, as `#[non_exhaustive]` allows, changes its layout")
+                .emit();
+            self.record(non_exhaustive, id, target, AttrCheckOutcome::Warning);
+        }
+    }
+
+    /// Warn on `#[inline]` on a trait method without a default body, there is
+    /// nothing to inline until an impl provides one.
+    fn check_required_method_inline(&self, trait_item: &hir::TraitItem) {
+        for attr in trait_item.attrs.iter().filter(|attr| attr.check_name("inline")) {
+            self.tcx.struct_span_lint_node(INLINE_ON_REQUIRED_TRAIT_METHODS,
+                                           trait_item.id,
+                                           attr.span,
+                                           "`#[inline]` has no effect on a trait method \
+                                            without a body")
+                .span_label(trait_item.span, "no default body to inline")
+                .help("put the attribute on the method in each impl instead")
+                .emit();
+            self.record(attr, trait_item.id, Target::Fn, AttrCheckOutcome::Warning);
+        }
+    }
+
+    /// Check that a `#[naked]` function spells out its ABI. Without a prologue,
+    /// its body has to be written for a calling convention known in advance,
+    /// which the Rust ABI is not.
+    fn check_naked(&self, id: NodeId, attr: &hir::Attribute, span: &Span) {
+        if self.tcx.fn_sig(self.tcx.hir.local_def_id(id)).abi() != Abi::Rust {
+            return;
+        }
+        let def_span = self.tcx.sess.codemap().def_span(*span);
+        struct_span_err!(self.tcx.sess, def_span, E0731,
+                         "naked function must have an explicit ABI")
+            .span_label(def_span, "uses the Rust ABI, which is unspecified")
+            .span_label(attr.span, "the function is naked here")
+            .help("declare it with the calling convention its body is written for, \
+                   like `extern \"C\" fn`")
+            .emit();
+        self.record(attr, id, Target::Fn, AttrCheckOutcome::Error);
+    }
+
+    /// Check that a `#[track_caller]` function can be passed the location of its
+    /// caller, as an implicit argument only the Rust ABI has room for, and only
+    /// direct calls can pass.
+    fn check_track_caller(&self, id: NodeId, attr: &hir::Attribute, span: &Span) {
+        let def_id = self.tcx.hir.local_def_id(id);
+        let def_span = self.tcx.sess.codemap().def_span(*span);
+        let abi = self.tcx.fn_sig(def_id).abi();
+        if abi != Abi::Rust {
+            struct_span_err!(self.tcx.sess, def_span, E0733,
+                             "`#[track_caller]` requires the Rust ABI")
+                .span_label(def_span, format!("declared `extern \"{}\"`", abi.name()))
+                .span_label(attr.span, "the caller's location is requested here")
+                .emit();
+            self.record(attr, id, Target::Fn, AttrCheckOutcome::Error);
+            return;
+        }
+
+        let in_trait = match self.tcx.opt_associated_item(def_id).map(|item| item.container) {
+            Some(ty::TraitContainer(_)) => true,
+            Some(ty::ImplContainer(impl_def_id)) => self.tcx.impl_trait_ref(impl_def_id).is_some(),
+            None => false,
+        };
+        if in_trait {
+            struct_span_err!(self.tcx.sess, def_span, E0734,
+                             "`#[track_caller]` is not supported on trait methods")
+                .span_label(def_span, "may be called through a trait object")
+                .note("a call through a trait object cannot pass the location of its caller")
+                .emit();
+            self.record(attr, id, Target::Fn, AttrCheckOutcome::Error);
+        }
+    }
+
+    /// Check that the target supports the thread-local storage `#[thread_local]`
+    /// asks for, rather than leaving it to fail during codegen.
+    fn check_thread_local(&self, id: NodeId, attr: &hir::Attribute, target: Target) {
+        if self.tcx.sess.target.target.options.has_elf_tls {
+            return;
+        }
+        self.tcx.sess.struct_span_err(attr.span,
+                                      "`#[thread_local]` is not supported on this target")
+            .note(&format!("target `{}` has no support for thread-local storage",
+                           self.tcx.sess.opts.target_triple))
+            .emit();
+        self.record(attr, id, target, AttrCheckOutcome::Error);
+    }
+
+    /// Check that an item is not marked both `#[inline(always)]` and
+    /// `#[inline(never)]`, of which codegen would silently pick the last one.
+    /// Returns `true` if it is, and that was reported.
+    fn check_inline_conflict(&self, id: NodeId, attrs: &[hir::Attribute], target: Target)
+                             -> bool {
+        let find = |hint: &str| attrs.iter().find(|attr| {
+            attr.check_name("inline") && !is_derive_generated(attr.span) &&
+                attr::list_contains_name(&attr.meta_item_list().unwrap_or_default(), hint)
+        });
+        let (always, never) = match (find("always"), find("never")) {
+            (Some(always), Some(never)) => (always, never),
+            _ => return false,
+        };
+        struct_span_err!(self.tcx.sess, vec![always.span, never.span], E0721,
+                         "conflicting `#[inline]` hints")
+            .span_label(always.span, "asks for every call to be inlined")
+            .span_label(never.span, "asks for no call to be inlined")
+            .span_suggestion_with_applicability(always.span,
+                                                "remove `#[inline(always)]`",
+                                                String::new(),
+                                                Applicability::MaybeIncorrect)
+            .span_suggestion_with_applicability(never.span,
+                                                "remove `#[inline(never)]`",
+                                                String::new(),
+                                                Applicability::MaybeIncorrect)
+            .emit();
+        let last = if always.span.lo() < never.span.lo() { never } else { always };
+        self.record(last, id, target, AttrCheckOutcome::Error);
+        true
+    }
+
+    /// Check if the `#[repr]` attributes on `item` are valid.
+    fn check_repr(&self,
+                  id: NodeId,
+                  attrs: &[hir::Attribute],
+                  span: &Span,
+                  target: Target,
+                  item: Option<&hir::Item>) {
+        // Extract the names of all repr hints, e.g., [foo, bar, align] for:
+        // ```
+        // #[repr(foo)]
+        // #[repr(bar, align(8))]
+        // ```
+        //
+        // Each hint is paired with the attribute it was written in and the span to
+        // report it at. Hints written inside `#[cfg_attr(.., repr(..))]` keep the
+        // span of the user's `repr(..)`, but fall back to the span of the
+        // attribute they were expanded into if the expansion left them without a
+        // real one.
+        let hints: Vec<_> = attrs
+            .iter()
+            .filter(|attr| attr.path == "repr")
+            .filter_map(|attr| attr.meta_item_list().map(|hints| (attr, hints)))
+            .flat_map(|(attr, hints)| {
+                hints.into_iter().map(move |hint| {
+                    (attr, hint.span.substitute_dummy(attr.span), hint)
+                })
+            })
+            .collect();
+
+        // `#[repr()]` was deliberately left empty, and does nothing.
+        for attr in attrs.iter().filter(|attr| attr.path == "repr") {
+            if attr.meta_item_list().map_or(false, |list| list.is_empty()) {
+                let mut err = self.tcx.struct_span_lint_node(BAD_REPR,
+                                                             id,
+                                                             attr.span,
+                                                             "`repr` attribute has no hints");
+                suggest_removal(&mut err, attr);
+                err.emit();
+            }
+        }
+
+        // Foreign items, and the blocks declaring them, are laid out as their ABI
+        // dictates, and associated items don't have a layout of their own. Say so,
+        // rather than listing the targets `repr` can be used on.
+        const FOREIGN_MOD_NOTE: &str =
+            "the items of an `extern` block have the representation required by its ABI";
+        const FOREIGN_ITEM_NOTE: &str =
+            "foreign items have the representation required by the ABI they are declared with";
+        const VARIANT_NOTE: &str =
+            "the layout of the variants of an enum is controlled by the `#[repr]` on the enum";
+        const UNION_FIELD_NOTE: &str =
+            "every field of a union starts at its beginning and has the representation of its \
+             type, use `#[repr]` on the union to control its layout";
+        let never_valid = match target {
+            Target::ForeignMod => Some(("an `extern` block", Some(FOREIGN_MOD_NOTE))),
+            Target::ForeignFn => Some(("a foreign function", Some(FOREIGN_ITEM_NOTE))),
+            Target::ForeignStatic => Some(("a foreign static", Some(FOREIGN_ITEM_NOTE))),
+            Target::ForeignTy => Some(("a foreign type", Some(FOREIGN_ITEM_NOTE))),
+            Target::Const if self.is_associated_item(id) => Some(("an associated const", None)),
+            Target::TyAlias if self.is_associated_item(id) => Some(("an associated type", None)),
+            Target::Variant => Some(("an enum variant", Some(VARIANT_NOTE))),
+            Target::Field if self.is_union_field(id) => {
+                Some(("a union field", Some(UNION_FIELD_NOTE)))
+            }
+            Target::Field => Some(("a field", None)),
+            _ => None,
+        };
+        if let Some((description, note)) = never_valid {
+            for &(attr, hint_span, _) in &hints {
+                let mut err = struct_span_err!(self.tcx.sess, hint_span, E0517,
+                                               "attribute should not be applied to {}",
+                                               description);
+                err.span_label(*span, "not a struct, enum or union");
+                if let Some(note) = note {
+                    err.note(note);
+                }
+                note_macro_backtrace(&mut err, hint_span);
+                // Variants and fields are where a misplaced `#[repr]` is most often
+                // meant for the enclosing type. Offer to remove it when that doesn't
+                // take any other hint along with it.
+                let hints_in_attr = hints.iter().filter(|&&(other, ..)| other.id == attr.id);
+                if hints_in_attr.count() == 1 &&
+                   (target == Target::Variant || target == Target::Field) {
+                    suggest_removal(&mut err, attr);
+                }
+                err.emit();
+                self.record(attr, id, target, AttrCheckOutcome::Error);
+            }
+            return;
+        }
+
+        // Only types have a layout. Anywhere else, report each `#[repr]` once
+        // rather than once for each of its hints.
+        if target != Target::Struct && target != Target::Union && target != Target::Enum {
+            let mut reported = vec![];
+            for &(attr, ..) in &hints {
+                if reported.contains(&attr.id) {
+                    continue;
+                }
+                reported.push(attr.id);
+                let (hint_spans, hint_names): (Vec<_>, Vec<_>) = hints.iter()
+                    .filter(|&&(other, ..)| other.id == attr.id)
+                    .filter_map(|&(_, hint_span, ref hint)| {
+                        hint.name()
+                            .filter(|name| is_known_repr_hint(&name.as_str()))
+                            .map(|name| (hint_span, self.hint_snippet(hint_span, name)))
+                    })
+                    .unzip();
+                if hint_spans.is_empty() {
+                    continue;
+                }
+                let note = match target {
+                    Target::Static | Target::Const => {
+                        Some("the representation of a static or constant is that of its type, \
+                              to control it use a type with a `#[repr]` attribute")
+                    }
+                    _ => None,
+                };
+                self.emit_repr_error(
+                    hint_spans,
+                    *span,
+                    &format!("`repr({})` attribute should be applied to struct, enum or union",
+                             hint_names.join(", ")),
+                    "not a struct, enum or union",
+                    note,
+                    None,
+                );
+                self.record(attr, id, target, AttrCheckOutcome::Error);
             }
+            return;
         }
 
-        if attr.meta_item_list().is_some() || attr.value_str().is_some() {
-            struct_span_err!(self.tcx.sess,
-                             attr.span,
-                             E0702,
-                             "attribute should be empty")
-                .span_label(item.span, "not empty")
-                .emit();
-        }
-    }
-
-    /// Check if the `#[repr]` attributes on `item` are valid.
-    fn check_repr(&self, item: &hir::Item, target: Target) {
-        // Extract the names of all repr hints, e.g., [foo, bar, align] for:
-        // ```
-        // #[repr(foo)]
-        // #[repr(bar, align(8))]
-        // ```
-        let hints: Vec<_> = item.attrs
-            .iter()
-            .filter(|attr| attr.name() == "repr")
-            .filter_map(|attr| attr.meta_item_list())
-            .flat_map(|hints| hints)
-            .collect();
-
-        let mut int_reprs = 0;
-        let mut is_c = false;
-        let mut is_simd = false;
+        let mut int_hints = vec![];
+        let mut c_span = None;
+        let mut packed_span = None;
+        let mut packs = vec![];
+        let mut align_span = None;
+        let mut simd_span = None;
         let mut is_transparent = false;
 
-        for hint in &hints {
+        for &(attr, hint_span, ref hint) in &hints {
             let name = if let Some(name) = hint.name() {
                 name
             } else {
@@ -166,116 +1731,538 @@ impl<'a, 'tcx> CheckAttrVisitor<'a, 'tcx> {
                 // (libsyntax does that), so just ignore it.
                 continue;
             };
+            if !self.check_repr_hint_args(&name.as_str(), hint, hint_span) {
+                self.record(attr, id, target, AttrCheckOutcome::Error);
+                continue;
+            }
 
-            let (article, allowed_targets) = match &*name.as_str() {
-                "C" => {
-                    is_c = true;
-                    if target != Target::Struct &&
-                            target != Target::Union &&
-                            target != Target::Enum {
-                                ("a", "struct, enum or union")
-                    } else {
-                        continue
-                    }
-                }
-                "packed" => {
-                    if target != Target::Struct &&
-                            target != Target::Union {
-                                ("a", "struct or union")
+            let repr_hint = match repr_hint(&name.as_str()) {
+                Some(repr_hint) => repr_hint,
+                None => continue,
+            };
+            match repr_hint.kind {
+                ReprHintKind::C => c_span = Some(hint_span),
+                ReprHintKind::Packed => {
+                    packed_span = packed_span.or(Some(hint_span));
+                    let pack = if hint.is_word() {
+                        Some(1)
                     } else {
-                        continue
+                        self.check_alignment_arg(id, attr, "packed", hint, hint_span, target)
+                    };
+                    if let Some(pack) = pack {
+                        packs.push((pack, hint_span));
                     }
                 }
-                "simd" => {
-                    is_simd = true;
-                    if target != Target::Struct {
-                        ("a", "struct")
-                    } else {
-                        continue
+                ReprHintKind::Simd => simd_span = Some(hint_span),
+                ReprHintKind::Align => {
+                    align_span = align_span.or(Some(hint_span));
+                    // `align = N` is reported, with a suggestion, by libsyntax.
+                    let is_name_value = match hint.meta_item() {
+                        Some(&ast::MetaItem { node: ast::MetaItemKind::NameValue(_), .. }) => {
+                            true
+                        }
+                        _ => false,
+                    };
+                    if !is_name_value {
+                        self.check_alignment_arg(id, attr, "align", hint, hint_span, target);
                     }
-                }
-                "align" => {
-                    if target != Target::Struct &&
-                            target != Target::Union {
-                        ("a", "struct or union")
-                    } else {
+                    if target == Target::Enum {
+                        if !self.tcx.features().repr_align_enum {
+                            emit_feature_err(&self.tcx.sess.parse_sess,
+                                             "repr_align_enum",
+                                             hint_span,
+                                             GateIssue::Language,
+                                             "`#[repr(align(x))]` on enums is experimental");
+                            self.record(attr, id, target, AttrCheckOutcome::Error);
+                        }
                         continue
                     }
                 }
-                "transparent" => {
+                ReprHintKind::Transparent => {
                     is_transparent = true;
-                    if target != Target::Struct {
-                        ("a", "struct")
-                    } else {
+                    if target == Target::Union {
+                        if !self.tcx.features().transparent_unions {
+                            emit_feature_err(&self.tcx.sess.parse_sess,
+                                             "transparent_unions",
+                                             hint_span,
+                                             GateIssue::Language,
+                                             "transparent unions are unstable");
+                            self.record(attr, id, target, AttrCheckOutcome::Error);
+                        }
                         continue
                     }
-                }
-                "i8" | "u8" | "i16" | "u16" |
-                "i32" | "u32" | "i64" | "u64" |
-                "isize" | "usize" => {
-                    int_reprs += 1;
-                    if target != Target::Enum {
-                        ("an", "enum")
-                    } else {
+                    if target == Target::Enum {
+                        if !self.tcx.features().transparent_enums {
+                            emit_feature_err(&self.tcx.sess.parse_sess,
+                                             "transparent_enums",
+                                             hint_span,
+                                             GateIssue::Language,
+                                             "transparent enums are unstable");
+                            self.record(attr, id, target, AttrCheckOutcome::Error);
+                        }
                         continue
                     }
                 }
-                _ => continue,
-            };
+                ReprHintKind::Int => {
+                    int_hints.push((name, hint_span));
+                }
+            }
+            if repr_hint.targets.contains(&target) {
+                continue
+            }
+            let (article, allowed_targets) = repr_hint.allowed;
             self.emit_repr_error(
-                hint.span,
-                item.span,
-                &format!("attribute should be applied to {}", allowed_targets),
-                &format!("not {} {}", article, allowed_targets),
-            )
+                hint_span,
+                *span,
+                &format!("`repr({})` attribute should be applied to {}",
+                         self.hint_snippet(hint_span, name),
+                         allowed_targets),
+                &format!("not {} {}, which `{}` requires", article, allowed_targets, name),
+                None,
+                None,
+            );
+            self.record(attr, id, target, AttrCheckOutcome::Error);
         }
 
-        // Just point at all repr hints if there are any incompatibilities.
-        // This is not ideal, but tracking precisely which ones are at fault is a huge hassle.
-        let hint_spans = hints.iter().map(|hint| hint.span);
-
         // Error on repr(transparent, <anything else>).
         if is_transparent && hints.len() > 1 {
-            let hint_spans: Vec<_> = hint_spans.clone().collect();
+            let hint_spans: Vec<_> = hints.iter().map(|&(_, hint_span, _)| hint_span).collect();
+            let kind = match target {
+                Target::Enum => "enum",
+                Target::Union => "union",
+                _ => "struct",
+            };
             span_err!(self.tcx.sess, hint_spans, E0692,
-                      "transparent struct cannot have other repr hints");
+                      "transparent {} cannot have other repr hints", kind);
+        }
+        // Warn on repr(u8, u16), repr(C, simd), and c-like-enum-repr(C, u8), pointing
+        // at the pairs of hints that conflict.
+        let mut conflicts = vec![];
+        if let Some(&(first_name, first_span)) = int_hints.first() {
+            for &(name, span) in &int_hints[1..] {
+                conflicts.push((first_span, span,
+                                format!("`{}` conflicts with `{}`", name, first_name)));
+            }
+        }
+        if let (Some(c_span), Some(simd_span)) = (c_span, simd_span) {
+            conflicts.push((c_span, simd_span, "`simd` conflicts with `C`".to_string()));
+        }
+        if let (Some(c_span), Some(&(int_name, int_span))) = (c_span, int_hints.first()) {
+            if int_hints.len() == 1 && item.map_or(false, is_c_like_enum) {
+                conflicts.push((c_span, int_span,
+                                format!("`{}` conflicts with `C` on an enum without fields",
+                                        int_name)));
+            }
+        }
+        if !conflicts.is_empty() {
+            let mut spans = vec![];
+            for &(first, second, _) in &conflicts {
+                for &span in &[first, second] {
+                    if !spans.contains(&span) {
+                        spans.push(span);
+                    }
+                }
+            }
+            let mut err = self.tcx.struct_span_lint_node(CONFLICTING_REPR_HINTS,
+                                                         id,
+                                                         spans,
+                                                         "conflicting representation hints");
+            err.code(DiagnosticId::Error("E0566".to_owned()));
+            for &(_, _, ref note) in &conflicts {
+                err.note(note);
+            }
+            err.emit();
+        }
+
+        // repr(C, u8) is valid on an enum with fields, but gives it the layout of a
+        // `repr(C)` union of `repr(C)` structs, each starting with a `u8` tag.
+        let int_repr = if int_hints.len() == 1 { int_hints.first() } else { None };
+        if let (Some(c_span), Some(&(int_name, int_span)), Some(item)) = (c_span, int_repr, item) {
+            if target == Target::Enum && !is_c_like_enum(item) {
+                self.tcx.struct_span_lint_node(REPR_C_DATA_ENUMS_WITH_INT,
+                                               id,
+                                               vec![c_span, int_span],
+                                               &format!("enum with fields has both `C` and \
+                                                         `{}` representation hints", int_name))
+                    .note(&format!("each variant is laid out as a `#[repr(C)]` struct \
+                                    starting with a `{}` tag, and the enum as a `#[repr(C)]` \
+                                    union of those structs", int_name))
+                    .emit();
+            }
+            if target == Target::Enum {
+                self.check_literal_discriminants(item, &int_name.as_str(), int_span);
+            }
+        }
+
+        // Error on repr(packed, align(N)), whether or not both hints are written in the
+        // same `#[repr]` attribute.
+        if target == Target::Struct || target == Target::Union {
+            if let (Some(packed_span), Some(align_span)) = (packed_span, align_span) {
+                struct_span_err!(self.tcx.sess, packed_span, E0587,
+                                 "type has conflicting packed and align representation hints")
+                    .span_label(packed_span, "packed here")
+                    .span_label(align_span, "aligned here")
+                    .note("`packed` lowers the alignment of a type while `align` raises it, \
+                           so a type cannot have both")
+                    .emit();
+            }
+        }
+
+        // Error on repr(packed, packed(2)): each hint asks for a different packing.
+        if packs.iter().any(|&(pack, _)| pack != packs[0].0) {
+            let spans: Vec<_> = packs.iter().map(|&(_, span)| span).collect();
+            let mut err = struct_span_err!(self.tcx.sess, spans, E0634,
+                                           "type has conflicting packed representation hints");
+            for &(pack, span) in &packs {
+                let bytes = if pack == 1 { "byte" } else { "bytes" };
+                err.span_label(span, format!("packed to {} {}", pack, bytes));
+            }
+            err.emit();
+        }
+
+        if let (Some(c_span), Some(item)) = (c_span, item) {
+            self.check_repr_c_fields(id, c_span, item);
+        }
+
+        self.check_multiple_repr_attributes(id, attrs);
+    }
+
+    /// Lint on a type whose representation hints are spread across several
+    /// `#[repr]` attributes, suggesting a single attribute with all of them.
+    fn check_multiple_repr_attributes(&self, id: NodeId, attrs: &[hir::Attribute]) {
+        let reprs: Vec<_> = attrs.iter()
+            .filter(|attr| attr.path == "repr" && attr.meta_item_list().is_some())
+            .collect();
+        if reprs.len() < 2 || reprs.iter().any(|attr| attr.span.ctxt() != SyntaxContext::empty()) {
+            return;
+        }
+
+        let mut lint = self.tcx.struct_span_lint_node(
+            MULTIPLE_REPR_ATTRIBUTES,
+            id,
+            reprs.iter().map(|attr| attr.span).collect::<Vec<_>>(),
+            "representation hints are split across multiple `#[repr]` attributes",
+        );
+        let codemap = self.tcx.sess.codemap();
+        let hints: Result<Vec<_>, _> = reprs.iter()
+            .flat_map(|attr| attr.meta_item_list().unwrap_or_default())
+            .map(|hint| codemap.span_to_snippet(hint.span))
+            .collect();
+        if let Ok(hints) = hints {
+            let mut parts = vec![(reprs[0].span, format!("#[repr({})]", hints.join(", ")))];
+            parts.extend(reprs[1..].iter().map(|attr| (attr.span, String::new())));
+            lint.multipart_suggestion_with_applicability("merge them into one attribute",
+                                                         parts,
+                                                         Applicability::MachineApplicable);
+        }
+        lint.emit();
+    }
+
+    /// Check that the literal discriminants of a `repr(C, <int>)` enum fit in
+    /// the integer type. Values that don't silently wrap around, and surface, if
+    /// at all, as a clash with the discriminant of another variant.
+    fn check_literal_discriminants(&self, item: &hir::Item, int_name: &str, int_span: Span) {
+        let def = match item.node {
+            hir::ItemEnum(ref def, _) => def,
+            _ => return,
+        };
+        let bits = match int_name {
+            "i8" | "u8" => 8,
+            "i16" | "u16" => 16,
+            "i32" | "u32" => 32,
+            "i64" | "u64" => 64,
+            "isize" | "usize" => self.tcx.data_layout.pointer_size.bits(),
+            _ => return,
+        };
+        let (min, max) = if int_name.starts_with('i') {
+            (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+        } else {
+            (0, (1i128 << bits) - 1)
+        };
+
+        for variant in &def.variants {
+            let expr = match variant.node.disr_expr {
+                Some(ref anon_const) => &self.tcx.hir.body(anon_const.body).value,
+                None => continue,
+            };
+            let (negative, value) = match expr.node {
+                hir::ExprLit(ref lit) => (false, &lit.node),
+                hir::ExprUnary(hir::UnNeg, ref inner) => match inner.node {
+                    hir::ExprLit(ref lit) => (true, &lit.node),
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            let value = match *value {
+                ast::LitKind::Int(value, _) => value,
+                _ => continue,
+            };
+            let fits = if negative {
+                value <= min.wrapping_neg() as u128
+            } else {
+                value <= max as u128
+            };
+            if !fits {
+                self.tcx.sess.struct_span_err(expr.span,
+                                              &format!("discriminant value `{}{}` does not fit \
+                                                        in `{}`",
+                                                       if negative { "-" } else { "" },
+                                                       value,
+                                                       int_name))
+                    .span_label(int_span, format!("discriminants are stored as `{}`", int_name))
+                    .note(&format!("`{}` holds values from {} to {}", int_name, min, max))
+                    .emit();
+            }
+        }
+    }
+
+    /// Whether the field `id` belongs to a union.
+    fn is_union_field(&self, id: NodeId) -> bool {
+        match self.tcx.hir.find(self.tcx.hir.get_parent(id)) {
+            Some(hir::map::NodeItem(&hir::Item { node: hir::ItemUnion(..), .. })) => true,
+            _ => false,
+        }
+    }
+
+    fn is_associated_item(&self, id: NodeId) -> bool {
+        match self.tcx.hir.find(id) {
+            Some(hir::map::NodeTraitItem(..)) | Some(hir::map::NodeImplItem(..)) => true,
+            _ => false,
+        }
+    }
+
+    /// Lint on `#[repr(C)]` structs and unions without fields, which have no
+    /// equivalent in C.
+    fn check_repr_c_fields(&self, id: NodeId, c_span: Span, item: &hir::Item) {
+        let (kind, data) = match item.node {
+            hir::ItemStruct(ref data, _) => ("struct", data),
+            hir::ItemUnion(ref data, _) => ("union", data),
+            _ => return,
+        };
+        if data.fields().is_empty() {
+            self.tcx.struct_span_lint_node(ZERO_SIZED_REPR_C,
+                                           id,
+                                           c_span,
+                                           &format!("`#[repr(C)]` {} has no fields", kind))
+                .span_label(item.span, format!("{} has no fields", kind))
+                .note("empty structs and unions are not allowed in C, and the sizes \
+                       C compilers give them as an extension do not match Rust's")
+                .emit();
+        }
+    }
+
+    /// Check that the repr hint `name` was written with the arguments it takes:
+    /// none for `C`, `simd`, `transparent` and the integer types, an optional
+    /// integer in parentheses for `packed` and a required one for `align`.
+    /// Returns `false` if an error was reported.
+    fn check_repr_hint_args(&self, name: &str, hint: &ast::NestedMetaItem, hint_span: Span)
+                            -> bool {
+        let node = match hint.meta_item() {
+            Some(meta) => &meta.node,
+            None => return true,
+        };
+        let repr_hint = match repr_hint(name) {
+            Some(repr_hint) => repr_hint,
+            None => return true,
+        };
+        let takes_int = repr_hint.args != ReprHintArgs::None;
+
+        let mut err = match *node {
+            ast::MetaItemKind::Word => match repr_hint.args {
+                ReprHintArgs::Int(help) => {
+                    let mut err = struct_span_err!(self.tcx.sess, hint_span, E0717,
+                                                   "`{}` representation hint needs an argument",
+                                                   name);
+                    err.help(help);
+                    err
+                }
+                ReprHintArgs::None | ReprHintArgs::OptionalInt => return true,
+            },
+            ast::MetaItemKind::List(ref args) if takes_int => {
+                if args.len() == 1 && args[0].is_literal() {
+                    return true;
+                }
+                struct_span_err!(self.tcx.sess, hint_span, E0717,
+                                 "`{}` representation hint takes exactly one integer argument",
+                                 name)
+            }
+            // `align = N` is reported, with a suggestion, by libsyntax.
+            ast::MetaItemKind::NameValue(_) if repr_hint.kind == ReprHintKind::Align => {
+                return true
+            }
+            ast::MetaItemKind::NameValue(ref lit) if takes_int => {
+                let mut err = struct_span_err!(self.tcx.sess, hint_span, E0717,
+                                               "incorrect `repr({})` hint format", name);
+                if let ast::LitKind::Int(int, ast::LitIntType::Unsuffixed) = lit.node {
+                    err.span_suggestion_with_applicability(hint_span,
+                                                           "use parentheses instead",
+                                                           format!("{}({})", name, int),
+                                                           Applicability::MachineApplicable);
+                }
+                err
+            }
+            ast::MetaItemKind::List(_) => {
+                let mut err = struct_span_err!(self.tcx.sess, hint_span, E0717,
+                                               "`{}` representation hint takes no arguments",
+                                               name);
+                err.span_suggestion_with_applicability(hint_span,
+                                                       "remove the arguments",
+                                                       name.to_string(),
+                                                       Applicability::MachineApplicable);
+                err
+            }
+            ast::MetaItemKind::NameValue(_) => {
+                let mut err = struct_span_err!(self.tcx.sess, hint_span, E0717,
+                                               "`{}` representation hint takes no value",
+                                               name);
+                err.span_suggestion_with_applicability(hint_span,
+                                                       "remove the value",
+                                                       name.to_string(),
+                                                       Applicability::MachineApplicable);
+                err
+            }
+        };
+        err.emit();
+        false
+    }
+
+    /// Check that the argument of `#[repr(align(N))]` or `#[repr(packed(N))]`,
+    /// named `name`, is an unsuffixed integer and a power of two, and for `align`
+    /// no larger than the target supports. Returns the argument if it is valid.
+    fn check_alignment_arg(&self,
+                           id: NodeId,
+                           attr: &hir::Attribute,
+                           name: &str,
+                           hint: &ast::NestedMetaItem,
+                           hint_span: Span,
+                           target: Target)
+                           -> Option<u128> {
+        let align = match hint.name_value_literal().map(|(_, lit)| &lit.node) {
+            Some(&ast::LitKind::Int(align, ast::LitIntType::Unsuffixed)) => align,
+            Some(&ast::LitKind::Int(align, _)) => {
+                struct_span_err!(self.tcx.sess, hint_span, E0722,
+                                 "`repr({})` argument must be an unsuffixed integer", name)
+                    .span_label(hint_span, "the argument has a suffix")
+                    .span_suggestion_with_applicability(hint_span,
+                                                        "remove the suffix",
+                                                        format!("{}({})", name, align),
+                                                        Applicability::MachineApplicable)
+                    .emit();
+                self.record(attr, id, target, AttrCheckOutcome::Error);
+                return None;
+            }
+            _ => {
+                struct_span_err!(self.tcx.sess, hint_span, E0722,
+                                 "`repr({})` argument must be an unsuffixed integer", name)
+                    .span_label(hint_span, "not an integer")
+                    .emit();
+                self.record(attr, id, target, AttrCheckOutcome::Error);
+                return None;
+            }
+        };
+
+        if !align.is_power_of_two() {
+            struct_span_err!(self.tcx.sess, hint_span, E0723,
+                             "`repr({})` argument must be a power of two", name)
+                .span_label(hint_span, format!("{} is not a power of two", align))
+                .emit();
+            self.record(attr, id, target, AttrCheckOutcome::Error);
+            return None;
         }
-        // Warn on repr(u8, u16), repr(C, simd), and c-like-enum-repr(C, u8)
-        if (int_reprs > 1)
-           || (is_simd && is_c)
-           || (int_reprs == 1 && is_c && is_c_like_enum(item)) {
-            let hint_spans: Vec<_> = hint_spans.collect();
-            span_warn!(self.tcx.sess, hint_spans, E0566,
-                       "conflicting representation hints");
+
+        if align > MAX_ALIGN {
+            struct_span_err!(self.tcx.sess, hint_span, E0724,
+                             "alignment of {} bytes is not supported", align)
+                .span_label(hint_span, "larger than 2^29 bytes, the largest supported alignment")
+                .emit();
+            self.record(attr, id, target, AttrCheckOutcome::Error);
+            return None;
+        }
+        // Packing only ever lowers the alignment of a type, so any packing is
+        // supported wherever the type is.
+        let max_align = match self.tcx.sess.target.target.options.max_align {
+            Some(max_align) if name == "align" => max_align,
+            _ => return Some(align),
+        };
+        if align > max_align as u128 {
+            struct_span_err!(self.tcx.sess, hint_span, E0724,
+                             "alignment of {} bytes is not supported on this target", align)
+                .span_label(hint_span, format!("larger than {} bytes", max_align))
+                .note(&format!("target `{}` supports alignments of at most {} bytes",
+                               self.tcx.sess.opts.target_triple,
+                               max_align))
+                .emit();
+            self.record(attr, id, target, AttrCheckOutcome::Error);
+            return None;
         }
+        Some(align)
+    }
+
+    /// The source of a repr hint, like `align(8)`, or just its name if that is
+    /// not available.
+    fn hint_snippet(&self, hint_span: Span, name: Name) -> String {
+        self.tcx.sess.codemap().span_to_snippet(hint_span).unwrap_or_else(|_| name.to_string())
     }
 
-    fn emit_repr_error(
+    /// Emit E0517 for a repr hint at `hint_span`, optionally with a note and a
+    /// suggestion replacing the given span.
+    fn emit_repr_error<S: Into<MultiSpan>>(
         &self,
-        hint_span: Span,
+        hint_span: S,
         label_span: Span,
         hint_message: &str,
         label_message: &str,
+        note: Option<&str>,
+        suggestion: Option<ReprSuggestion>,
     ) {
-        struct_span_err!(self.tcx.sess, hint_span, E0517, "{}", hint_message)
-            .span_label(label_span, label_message)
-            .emit();
+        let hint_span: MultiSpan = hint_span.into();
+        let mut err = struct_span_err!(self.tcx.sess, hint_span.clone(), E0517,
+                                       "{}", hint_message);
+        err.span_label(label_span, label_message);
+        if let Some(note) = note {
+            err.note(note);
+        }
+        if let Some(span) = hint_span.primary_span() {
+            note_macro_backtrace(&mut err, span);
+        }
+        if let Some(suggestion) = suggestion {
+            err.span_suggestion_with_applicability(suggestion.span,
+                                                   suggestion.msg,
+                                                   suggestion.replacement,
+                                                   suggestion.applicability);
+        }
+        err.emit();
     }
 
     fn check_stmt_attributes(&self, stmt: &hir::Stmt) {
-        // When checking statements ignore expressions, they will be checked later
-        if let hir::Stmt_::StmtDecl(_, _) = stmt.node {
+        // When checking statements ignore expressions, they will be checked later.
+        // Attributes produced by `#[cfg_attr]` were expanded before lowering and
+        // arrive here like any other, spanning the attribute inside the `cfg_attr`.
+        if let hir::Stmt_::StmtDecl(ref decl, id) = stmt.node {
+            let (target, description) = match decl.node {
+                hir::DeclLocal(_) => (Target::Local, "a `let` binding"),
+                hir::DeclItem(_) => (Target::Statement, "a statement"),
+            };
             for attr in stmt.node.attrs() {
-                if attr.check_name("inline") {
-                    self.check_inline(attr, &stmt.span, Target::Statement);
+                if self.check_target(id, attr, &stmt.span, target) {
+                    self.check_template(id, attr, target);
                 }
                 if attr.check_name("repr") {
+                    let note = if target == Target::Local {
+                        Some("the representation of a binding is that of its type, to control \
+                              it use a type with a `#[repr]` attribute")
+                    } else {
+                        None
+                    };
                     self.emit_repr_error(
                         attr.span,
                         stmt.span,
-                        &format!("attribute should not be applied to a statement"),
+                        &format!("attribute should not be applied to {}", description),
                         &format!("not a struct, enum or union"),
+                        note,
+                        Some(ReprSuggestion::remove(attr.span)),
                     );
+                    self.record(attr, id, target, AttrCheckOutcome::Error);
                 }
             }
         }
@@ -287,26 +2274,234 @@ impl<'a, 'tcx> CheckAttrVisitor<'a, 'tcx> {
             _ => Target::Expression,
         };
         for attr in expr.attrs.iter() {
-            if attr.check_name("inline") {
-                self.check_inline(attr, &expr.span, target);
+            if self.check_target(expr.id, attr, &expr.span, target) {
+                self.check_template(expr.id, attr, target);
+            }
+            if attr.check_name("repr") {
+                if target == Target::Closure {
+                    struct_span_err!(self.tcx.sess, attr.span, E0517,
+                                     "attribute should not be applied to a closure")
+                        .span_label(expr.span, "not defining a struct, enum or union")
+                        .note("the layout of a closure's captured state is chosen by the \
+                               compiler and cannot be controlled with `#[repr]`")
+                        .emit();
+                } else {
+                    self.emit_repr_error(
+                        attr.span,
+                        expr.span,
+                        &format!("attribute should not be applied to an expression"),
+                        &format!("not defining a struct, enum or union"),
+                        None,
+                        Some(ReprSuggestion::remove(attr.span)),
+                    );
+                }
+                self.record(attr, expr.id, target, AttrCheckOutcome::Error);
+            }
+        }
+    }
+
+    /// Check the attributes of a match arm. Arms are only ever expected to carry
+    /// `#[cfg]`, which has been expanded away by now.
+    fn check_arm_attributes(&self, arm: &hir::Arm) {
+        let span = arm.pats[0].span.to(arm.body.span);
+        for attr in arm.attrs.iter() {
+            if self.check_target(arm.body.id, attr, &span, Target::Arm) {
+                self.check_template(arm.body.id, attr, Target::Arm);
             }
             if attr.check_name("repr") {
                 self.emit_repr_error(
                     attr.span,
-                    expr.span,
-                    &format!("attribute should not be applied to an expression"),
+                    span,
+                    &format!("attribute should not be applied to a match arm"),
                     &format!("not defining a struct, enum or union"),
+                    None,
+                    Some(ReprSuggestion::remove(attr.span)),
                 );
+                self.record(attr, arm.body.id, Target::Arm, AttrCheckOutcome::Error);
+            }
+        }
+    }
+
+    /// Check if a `#[no_mangle]` function is generic, over its own type
+    /// parameters or, for a method, over those of the `impl` containing it.
+    /// Every instantiation would be emitted under the same unmangled symbol.
+    fn check_no_mangle_generics(&self,
+                                id: NodeId,
+                                attrs: &[hir::Attribute],
+                                span: Span,
+                                generics: &[&hir::Generics]) {
+        let attr = match attrs.iter().find(|attr| attr.check_name("no_mangle")) {
+            Some(attr) => attr,
+            None => return,
+        };
+        let generics = match generics.iter().find(|generics| generics.own_counts().types > 0) {
+            Some(generics) => generics,
+            None => return,
+        };
+
+        self.tcx.struct_span_lint_node(NO_MANGLE_GENERIC_ITEMS,
+                                       id,
+                                       span,
+                                       "functions generic over types must be mangled")
+            .span_label(generics.span, "generic over these types")
+            .note("every instantiation of this function would be emitted under the same \
+                   unmangled symbol name, causing the symbols to collide")
+            .span_suggestion_short(attr.span, "remove this attribute", String::new())
+            .emit();
+        self.record(attr, id, Target::Fn, AttrCheckOutcome::Warning);
+    }
+
+    /// Check that the symbol name given by an `#[export_name]` or `#[link_name]`,
+    /// or the section name given by a `#[link_section]`, can be passed on to the
+    /// linker.
+    fn check_symbol_name(&self, id: NodeId, attr: &hir::Attribute) {
+        let name = match attr.value_str() {
+            Some(name) => name,
+            None => return,
+        };
+        if !self.check_null_characters(attr) {
+            return;
+        }
+        if attr.check_name("export_name") || attr.check_name("link_section") {
+            self.check_export_name_chars(id, attr, &name.as_str());
+        }
+    }
+
+    /// Check that the name given by an attribute contains no null characters,
+    /// returning whether it does not.
+    fn check_null_characters(&self, attr: &hir::Attribute) -> bool {
+        match attr.value_str() {
+            Some(name) if name.as_str().contains('\0') => {}
+            _ => return true,
+        }
+        // The name will be converted to a null-terminated string, so it may not
+        // contain any null characters.
+        struct_span_err!(self.tcx.sess,
+                         value_span(attr),
+                         E0648,
+                         "`{}` may not contain null characters",
+                         attr.name())
+            .span_label(value_span(attr), "contains a null character")
+            .emit();
+        false
+    }
+
+    /// Warn about exported symbol and section names which are accepted by rustc
+    /// but are likely to be rejected or misinterpreted by the assembler or
+    /// linker. This is best-effort: the object formats accept almost any byte.
+    fn check_export_name_chars(&self, id: NodeId, attr: &hir::Attribute, name: &str) {
+        let problem = if let Some(c) = name.chars().find(|c| c.is_whitespace()) {
+            format!("contains the whitespace character {:?}", c)
+        } else if let Some(c) = name.chars().find(|c| c.is_control()) {
+            format!("contains the control character {:?}", c)
+        } else if self.tcx.sess.target.target.options.is_like_msvc &&
+                  attr.check_name("export_name") &&
+                  (name.starts_with('?') || name.starts_with('@')) {
+            format!("starts with `{}`, which the MSVC linker reserves for decorated names",
+                    &name[..1])
+        } else {
+            return;
+        };
+        let kind = if attr.check_name("link_section") { "section" } else { "symbol" };
+        self.tcx.struct_span_lint_node(INVALID_SYMBOL_NAMES,
+                                       id,
+                                       value_span(attr),
+                                       &format!("`{}` is not a valid {} name on this target",
+                                                attr.name(), kind))
+            .span_label(value_span(attr), problem)
+            .emit();
+    }
+
+    fn check_used(&self, id: NodeId, attrs: &[hir::Attribute], target: Target) {
+        // Warn on an exported #[used] static that is not placed in a specific section,
+        // retaining it is then rarely what was intended.
+        if target == Target::Static &&
+            attr::contains_name(attrs, "no_mangle") &&
+            !attr::contains_name(attrs, "link_section")
+        {
+            if let Some(attr) = attrs.iter().find(|attr| attr.path == "used") {
+                self.tcx.struct_span_lint_node(USED_WITHOUT_LINK_SECTION,
+                                               id,
+                                               attr.span,
+                                               "`#[used]` static is exported with `#[no_mangle]` \
+                                                but not placed in a section")
+                    .help("add `#[link_section = \"...\"]` to choose the section it is \
+                           retained in")
+                    .emit();
+            }
+        }
+
+        if target == Target::Static &&
+            !attr::contains_name(attrs, "no_mangle") &&
+            !attr::contains_name(attrs, "link_section")
+        {
+            if let Some(attr) = attrs.iter().find(|attr| attr.path == "used") {
+                self.unexported_used_statics.borrow_mut().push((id, attr.span));
             }
         }
     }
 
-    fn check_used(&self, item: &hir::Item, target: Target) {
-        for attr in &item.attrs {
-            if attr.name() == "used" && target != Target::Static {
-                self.tcx.sess
-                    .span_err(attr.span, "attribute must be applied to a `static` variable");
+    /// Lint on `#[no_mangle] static mut`: the symbol can be read and written from
+    /// other crates and foreign code without any synchronization, which makes
+    /// aliasing it easy to get wrong.
+    fn check_no_mangle_static_mut(&self,
+                                  id: NodeId,
+                                  attrs: &[hir::Attribute],
+                                  item: &hir::Item) {
+        let ty = match item.node {
+            hir::ItemStatic(ref ty, hir::MutMutable, _) => ty,
+            _ => return,
+        };
+        let no_mangle = match attrs.iter().find(|attr| attr.check_name("no_mangle")) {
+            Some(no_mangle) => no_mangle,
+            None => return,
+        };
+        let mut_span = self.mut_keyword_span(item.span.until(ty.span)).unwrap_or(item.span);
+        self.tcx.struct_span_lint_node(NO_MANGLE_STATIC_MUT,
+                                       id,
+                                       mut_span,
+                                       "`#[no_mangle]` static is mutable")
+            .span_label(mut_span, "mutable static exported under an unmangled name")
+            .span_label(no_mangle.span, "exported here")
+            .note("other crates and foreign code can access the symbol directly, so \
+                   references to it may alias with writes made elsewhere")
+            .help("use an immutable static of a type with interior mutability, such as \
+                   an atomic, instead")
+            .emit();
+        self.record(no_mangle, id, Target::Static, AttrCheckOutcome::Warning);
+    }
+
+    /// The span of the `mut` keyword following `static` in the source of a
+    /// `static` item's header.
+    fn mut_keyword_span(&self, header: Span) -> Option<Span> {
+        let snippet = self.tcx.sess.codemap().span_to_snippet(header).ok()?;
+        let after_static = snippet.find("static")? + "static".len();
+        let rest = &snippet[after_static..];
+        let start = after_static + (rest.len() - rest.trim_left().len());
+        if !snippet[start..].starts_with("mut") {
+            return None;
+        }
+        let lo = header.lo() + BytePos(start as u32);
+        Some(header.with_lo(lo).with_hi(lo + BytePos("mut".len() as u32)))
+    }
+
+    /// Lint on `#[used]` statics that nothing refers to, and that have neither a
+    /// symbol name nor a section anything outside the crate could find them by.
+    fn check_unreferenced_used_statics(&self) {
+        let referenced = self.referenced_statics.borrow();
+        for &(id, span) in self.unexported_used_statics.borrow().iter() {
+            if referenced.contains(&self.tcx.hir.local_def_id(id)) {
+                continue;
             }
+            self.tcx.struct_span_lint_node(UNREFERENCED_USED_STATICS,
+                                           id,
+                                           span,
+                                           "`#[used]` static is never referenced")
+                .note("it is kept in the object file, but under a mangled name and in \
+                       the default section, where nothing can rely on finding it")
+                .help("add `#[no_mangle]` or `#[link_section = \"...\"]` so it can be \
+                       located")
+                .emit();
         }
     }
 }
@@ -318,25 +2513,326 @@ impl<'a, 'tcx> Visitor<'tcx> for CheckAttrVisitor<'a, 'tcx> {
 
     fn visit_item(&mut self, item: &'tcx hir::Item) {
         let target = Target::from_item(item);
-        self.check_attributes(item, target);
+        let decl = match item.node {
+            hir::ItemFn(ref decl, ..) => Some(&**decl),
+            _ => None,
+        };
+        self.check_attributes(item.id, &item.attrs, &item.span, target, Some(item), decl);
         intravisit::walk_item(self, item)
     }
 
+    fn visit_impl_item(&mut self, impl_item: &'tcx hir::ImplItem) {
+        let target = Target::from_impl_item(impl_item);
+        let decl = match impl_item.node {
+            hir::ImplItemKind::Method(ref sig, _) => Some(&*sig.decl),
+            _ => None,
+        };
+        self.check_attributes(impl_item.id,
+                              &impl_item.attrs,
+                              &impl_item.span,
+                              target,
+                              None,
+                              decl);
+        if let hir::ImplItemKind::Method(..) = impl_item.node {
+            let parent = self.tcx.hir.get_parent(impl_item.id);
+            if let hir::ItemImpl(_, _, _, ref impl_generics, ..) =
+                    self.tcx.hir.expect_item(parent).node {
+                self.check_no_mangle_generics(impl_item.id,
+                                              &impl_item.attrs,
+                                              impl_item.span,
+                                              &[&impl_item.generics, impl_generics]);
+            }
+        }
+        intravisit::walk_impl_item(self, impl_item)
+    }
+
+    fn visit_trait_item(&mut self, trait_item: &'tcx hir::TraitItem) {
+        let target = Target::from_trait_item(trait_item);
+        let decl = match trait_item.node {
+            hir::TraitItemKind::Method(ref sig, _) => Some(&*sig.decl),
+            _ => None,
+        };
+        self.check_attributes(trait_item.id,
+                              &trait_item.attrs,
+                              &trait_item.span,
+                              target,
+                              None,
+                              decl);
+        if let hir::TraitItemKind::Method(_, hir::TraitMethod::Required(_)) = trait_item.node {
+            self.check_required_method_inline(trait_item);
+        }
+        intravisit::walk_trait_item(self, trait_item)
+    }
+
+    fn visit_foreign_item(&mut self, foreign_item: &'tcx hir::ForeignItem) {
+        let target = Target::from_foreign_item(foreign_item);
+        self.check_attributes(foreign_item.id,
+                              &foreign_item.attrs,
+                              &foreign_item.span,
+                              target,
+                              None,
+                              None);
+        intravisit::walk_foreign_item(self, foreign_item)
+    }
+
+    fn visit_variant(&mut self,
+                     variant: &'tcx hir::Variant,
+                     generics: &'tcx hir::Generics,
+                     item_id: NodeId) {
+        self.check_attributes(variant.node.data.id(),
+                              &variant.node.attrs,
+                              &variant.span,
+                              Target::Variant,
+                              None,
+                              None);
+        intravisit::walk_variant(self, variant, generics, item_id)
+    }
+
+    fn visit_struct_field(&mut self, field: &'tcx hir::StructField) {
+        self.check_attributes(field.id, &field.attrs, &field.span, Target::Field, None, None);
+        intravisit::walk_struct_field(self, field)
+    }
 
     fn visit_stmt(&mut self, stmt: &'tcx hir::Stmt) {
         self.check_stmt_attributes(stmt);
         intravisit::walk_stmt(self, stmt)
     }
 
+    fn visit_arm(&mut self, arm: &'tcx hir::Arm) {
+        self.check_arm_attributes(arm);
+        intravisit::walk_arm(self, arm)
+    }
+
     fn visit_expr(&mut self, expr: &'tcx hir::Expr) {
+        if let hir::ExprPath(hir::QPath::Resolved(_, ref path)) = expr.node {
+            if let Def::Static(def_id, _) = path.def {
+                self.referenced_statics.borrow_mut().insert(def_id);
+            }
+        }
         self.check_expr_attributes(expr);
         intravisit::walk_expr(self, expr)
     }
 }
 
+pub fn provide(providers: &mut Providers) {
+    *providers = Providers {
+        check_crate_attrs,
+        attr_check_results,
+        check_mod_attrs,
+        ..*providers
+    };
+}
+
 pub fn check_crate<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>) {
-    let mut checker = CheckAttrVisitor { tcx };
-    tcx.hir.krate().visit_all_item_likes(&mut checker.as_deep_visitor());
+    tcx.check_crate_attrs(LOCAL_CRATE);
+}
+
+/// The reason given by the `#[must_use]` in `attrs`, for the `unused_must_use`
+/// lint: `Some(None)` for `#[must_use]`, `Some(Some(reason))` for
+/// `#[must_use = "reason"]`, and `None` if there is neither. `#[must_use]` in
+/// any other form was reported when its item was checked, and is ignored.
+pub fn must_use_reason(attrs: &[ast::Attribute]) -> Option<Option<Symbol>> {
+    attrs.iter()
+        .find(|attr| {
+            attr.check_name("must_use") && (attr.is_word() || attr.value_str().is_some())
+        })
+        .map(|attr| attr.value_str())
+}
+
+fn check_crate_attrs<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                               krate: CrateNum)
+                               -> Lrc<Vec<AttrCheckResult>> {
+    assert_eq!(krate, LOCAL_CRATE);
+
+    let checker = CheckAttrVisitor::new(tcx);
+    checker.check_crate_attributes();
+    for &module in tcx.hir.krate().modules.keys() {
+        checker.add_item_checks(&tcx.check_mod_attrs(tcx.hir.local_def_id(module)));
+    }
+    checker.check_exported_macros();
+    checker.check_crate_duplicates();
+    checker.check_unreferenced_used_statics();
+    checker.check_no_std_panic_implementation();
+    Lrc::new(checker.results.into_inner())
+}
+
+fn attr_check_results<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                krate: CrateNum)
+                                -> Lrc<Vec<AttrCheckResult>> {
+    assert_eq!(krate, LOCAL_CRATE);
+
+    let mut results = (*tcx.check_crate_attrs(LOCAL_CRATE)).clone();
+    for &module in tcx.hir.krate().modules.keys() {
+        let checks = tcx.check_mod_attrs(tcx.hir.local_def_id(module));
+        results.extend(checks.results.iter().cloned());
+    }
+    Lrc::new(results)
+}
+
+fn check_mod_attrs<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                             module_def_id: DefId)
+                             -> Lrc<ItemAttrChecks> {
+    let mut checker = CheckAttrVisitor::new(tcx);
+    tcx.hir.visit_item_likes_in_module(module_def_id, &mut checker.as_deep_visitor());
+    Lrc::new(checker.into_item_checks())
+}
+
+/// Whether an attribute at `span` was written by a `#[derive]` rather than by
+/// the user. The items generated by built-in derives carry attributes like
+/// `#[inline]`, which the user could not act on a diagnostic about.
+fn is_derive_generated(span: Span) -> bool {
+    match span.ctxt().outer().expn_info() {
+        Some(ExpnInfo { format: ExpnFormat::MacroAttribute(name), .. }) => {
+            name.as_str().starts_with("derive(")
+        }
+        _ => false,
+    }
+}
+
+/// Offer to remove `attr`, which has no use where it is written.
+fn suggest_removal(err: &mut DiagnosticBuilder, attr: &hir::Attribute) {
+    err.span_suggestion_with_applicability(attr.span,
+                                           "remove the attribute",
+                                           String::new(),
+                                           Applicability::MachineApplicable);
+}
+
+/// Point at each `macro_rules!` invocation `span` was expanded from, innermost
+/// first, as the spans inside the macro alone don't say where the item came from.
+fn note_macro_backtrace(err: &mut DiagnosticBuilder, span: Span) {
+    for frame in span.macro_backtrace() {
+        if frame.macro_decl_name.ends_with('!') {
+            err.span_note(frame.call_site,
+                          &format!("the attribute was generated by this invocation of `{}`",
+                                   frame.macro_decl_name));
+        }
+    }
+}
+
+/// The largest alignment `#[repr(align)]` and `#[repr(packed)]` accept, as
+/// `ty::layout::Align` can't represent anything larger.
+const MAX_ALIGN: u128 = 1 << 29;
+
+/// The kinds of layout a `#[repr]` hint can ask for.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum ReprHintKind {
+    C,
+    Packed,
+    Simd,
+    Align,
+    Transparent,
+    Int,
+}
+
+/// The arguments a `#[repr]` hint takes.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum ReprHintArgs {
+    /// None, like `C`.
+    None,
+    /// Optionally one integer, like `packed` or `packed(2)`.
+    OptionalInt,
+    /// Exactly one integer, like `align(8)`, with help on what it means.
+    Int(&'static str),
+}
+
+/// Hints `#[repr]` accepts, which only differ in their name.
+struct ReprHint {
+    names: &'static [&'static str],
+    kind: ReprHintKind,
+    args: ReprHintArgs,
+    /// The targets the hint can be applied to.
+    targets: &'static [Target],
+    /// The targets the hint can be applied to, for diagnostics, with their article.
+    allowed: (&'static str, &'static str),
+}
+
+/// Every hint `#[repr]` accepts. Unknown hints are reported while parsing the
+/// attribute.
+const REPR_HINTS: &[ReprHint] = &[
+    ReprHint {
+        names: &["C"],
+        kind: ReprHintKind::C,
+        args: ReprHintArgs::None,
+        targets: &[Target::Struct, Target::Union, Target::Enum],
+        allowed: ("a", "struct, enum or union"),
+    },
+    ReprHint {
+        names: &["packed"],
+        kind: ReprHintKind::Packed,
+        args: ReprHintArgs::OptionalInt,
+        targets: &[Target::Struct, Target::Union],
+        allowed: ("a", "struct or union"),
+    },
+    ReprHint {
+        names: &["simd"],
+        kind: ReprHintKind::Simd,
+        args: ReprHintArgs::None,
+        targets: &[Target::Struct],
+        allowed: ("a", "struct"),
+    },
+    // Aligned enums are accepted behind a feature gate.
+    ReprHint {
+        names: &["align"],
+        kind: ReprHintKind::Align,
+        args: ReprHintArgs::Int("specify the alignment in bytes, e.g. `align(8)`"),
+        targets: &[Target::Struct, Target::Union],
+        allowed: ("a", "struct or union"),
+    },
+    // Transparent unions and enums are accepted behind feature gates.
+    ReprHint {
+        names: &["transparent"],
+        kind: ReprHintKind::Transparent,
+        args: ReprHintArgs::None,
+        targets: &[Target::Struct],
+        allowed: ("a", "struct"),
+    },
+    ReprHint {
+        names: &["i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "i128", "u128",
+                 "isize", "usize"],
+        kind: ReprHintKind::Int,
+        args: ReprHintArgs::None,
+        targets: &[Target::Enum],
+        allowed: ("an", "enum"),
+    },
+];
+
+fn repr_hint(name: &str) -> Option<&'static ReprHint> {
+    REPR_HINTS.iter().find(|hint| hint.names.contains(&name))
+}
+
+/// Whether `name` is a hint `#[repr]` accepts.
+fn is_known_repr_hint(name: &str) -> bool {
+    repr_hint(name).is_some()
+}
+
+fn is_int_repr_hint(name: &str) -> bool {
+    repr_hint(name).map_or(false, |hint| hint.kind == ReprHintKind::Int)
+}
+
+/// The arguments `#[link(..)]` accepts.
+const LINK_KEYS: &[&str] = &["name", "kind", "cfg"];
+
+/// The values accepted for `kind` in `#[link(..)]`.
+const LINK_KINDS: &[&str] = &["static", "static-nobundle", "dylib", "framework"];
+
+/// A fix offered alongside a repr error.
+struct ReprSuggestion {
+    span: Span,
+    msg: &'static str,
+    replacement: String,
+    applicability: Applicability,
+}
+
+impl ReprSuggestion {
+    /// Remove a `#[repr]` attribute which can never be valid where it is written.
+    fn remove(attr_span: Span) -> ReprSuggestion {
+        ReprSuggestion {
+            span: attr_span,
+            msg: "remove this attribute",
+            replacement: String::new(),
+            applicability: Applicability::MachineApplicable,
+        }
+    }
 }
 
 fn is_c_like_enum(item: &hir::Item) -> bool {
@@ -352,3 +2848,12 @@ fn is_c_like_enum(item: &hir::Item) -> bool {
         false
     }
 }
+
+/// Returns the span of the value of a `#[name = "value"]` attribute, or the
+/// span of the whole attribute if it does not have that form.
+fn value_span(attr: &hir::Attribute) -> Span {
+    match attr.meta() {
+        Some(ast::MetaItem { node: ast::MetaItemKind::NameValue(ref lit), .. }) => lit.span,
+        _ => attr.span,
+    }
+}
@@ -28,6 +28,7 @@ use syntax::ext::base::MacroKind;
 use syntax_pos::{Span, DUMMY_SP};
 
 use hir::*;
+use hir::itemlikevisit::ItemLikeVisitor;
 use hir::print::Nested;
 use hir::svh::Svh;
 use util::nodemap::FxHashMap;
@@ -193,6 +194,13 @@ impl<'hir> MapEntry<'hir> {
                 }
             }
 
+            EntryForeignItem(_, _, ref item) => {
+                match item.node {
+                    ForeignItemFn(ref fn_decl, _, _) => Some(&fn_decl),
+                    _ => None,
+                }
+            }
+
             EntryExpr(_, _, ref expr) => {
                 match expr.node {
                     ExprClosure(_, ref fn_decl, ..) => Some(&fn_decl),
@@ -641,6 +649,35 @@ impl<'hir> Map<'hir> {
         self.trait_auto_impl(trait_did).is_some()
     }
 
+    /// Visit every item-like in `module`, including those nested in the bodies
+    /// of its items, but not those in the modules it contains.
+    pub fn visit_item_likes_in_module<V>(&self, module: DefId, visitor: &mut V)
+        where V: ItemLikeVisitor<'hir>
+    {
+        let node_id = self.as_local_node_id(module).unwrap();
+
+        // Read the module so we'll be re-executed if new items appear
+        // immediately under it. An item added to the body of one of its items
+        // changes that item, which is read below.
+        self.read(node_id);
+
+        // NB: intentionally bypass `self.forest.krate()` so that we
+        // do not trigger a read of the whole krate here
+        let module = &self.forest.krate.modules[&node_id];
+
+        for &id in &module.items {
+            visitor.visit_item(self.expect_item(id));
+        }
+
+        for id in &module.trait_items {
+            visitor.visit_trait_item(self.expect_trait_item(id.node_id));
+        }
+
+        for id in &module.impl_items {
+            visitor.visit_impl_item(self.expect_impl_item(id.node_id));
+        }
+    }
+
     /// Get the attributes on the krate. This is preferable to
     /// invoking `krate.attrs` because it registers a tighter
     /// dep-graph access.
@@ -49,7 +49,7 @@ use rustc_data_structures::indexed_vec;
 use rustc_data_structures::sync::{ParallelIterator, par_iter, Send, Sync, scope};
 
 use serialize::{self, Encoder, Encodable, Decoder, Decodable};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
 /// HIR doesn't commit to a concrete storage type and has its own alias for a vector.
@@ -644,6 +644,17 @@ pub struct WhereEqPredicate {
 
 pub type CrateConfig = HirVec<P<MetaItem>>;
 
+/// The item-likes directly inside a module, including those nested in the
+/// bodies of its items, but not those of the modules inside it.
+#[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Debug)]
+pub struct ModuleItems {
+    // Use BTreeSets here so items are in the same order as
+    // the top-level `items`, `trait_items` and `impl_items` fields.
+    pub items: BTreeSet<NodeId>,
+    pub trait_items: BTreeSet<TraitItemId>,
+    pub impl_items: BTreeSet<ImplItemId>,
+}
+
 /// The top-level data structure that stores the entire contents of
 /// the crate currently being compiled.
 ///
@@ -676,6 +687,9 @@ pub struct Crate {
     /// in the crate, you should iterate over this list rather than the keys
     /// of bodies.
     pub body_ids: Vec<BodyId>,
+
+    /// The item-likes of each module, keyed by the id of the module.
+    pub modules: BTreeMap<NodeId, ModuleItems>,
 }
 
 impl Crate {
@@ -2259,6 +2273,7 @@ pub type GlobMap = NodeMap<FxHashSet<Name>>;
 
 pub fn provide(providers: &mut Providers) {
     providers.describe_def = map::describe_def;
+    check_attr::provide(providers);
 }
 
 #[derive(Clone, RustcEncodable, RustcDecodable, Hash)]
@@ -2273,15 +2288,16 @@ pub struct CodegenFnAttrs {
 
 bitflags! {
     #[derive(RustcEncodable, RustcDecodable)]
-    pub struct CodegenFnAttrFlags: u8 {
-        const COLD                      = 0b0000_0001;
-        const ALLOCATOR                 = 0b0000_0010;
-        const UNWIND                    = 0b0000_0100;
-        const RUSTC_ALLOCATOR_NOUNWIND  = 0b0000_1000;
-        const NAKED                     = 0b0001_0000;
-        const NO_MANGLE                 = 0b0010_0000;
-        const RUSTC_STD_INTERNAL_SYMBOL = 0b0100_0000;
-        const NO_DEBUG                  = 0b1000_0000;
+    pub struct CodegenFnAttrFlags: u16 {
+        const COLD                      = 0b0000_0000_0000_0001;
+        const ALLOCATOR                 = 0b0000_0000_0000_0010;
+        const UNWIND                    = 0b0000_0000_0000_0100;
+        const RUSTC_ALLOCATOR_NOUNWIND  = 0b0000_0000_0000_1000;
+        const NAKED                     = 0b0000_0000_0001_0000;
+        const NO_MANGLE                 = 0b0000_0000_0010_0000;
+        const RUSTC_STD_INTERNAL_SYMBOL = 0b0000_0000_0100_0000;
+        const NO_DEBUG                  = 0b0000_0000_1000_0000;
+        const TRACK_CALLER              = 0b0000_0001_0000_0000;
     }
 }
 
@@ -1140,6 +1140,34 @@ impl<'hir> HashStable<StableHashingContext<'hir>> for attr::InlineAttr {
     }
 }
 
+impl<'hir> HashStable<StableHashingContext<'hir>> for hir::check_attr::Target {
+    fn hash_stable<W: StableHasherResult>(&self,
+                                          hcx: &mut StableHashingContext<'hir>,
+                                          hasher: &mut StableHasher<W>) {
+        mem::discriminant(self).hash_stable(hcx, hasher);
+    }
+}
+
+impl_stable_hash_for!(enum hir::check_attr::AttrCheckOutcome {
+    Error,
+    Warning,
+    Allowed
+});
+
+impl_stable_hash_for!(struct hir::check_attr::AttrCheckResult {
+    attr_name,
+    def_id,
+    target,
+    outcome
+});
+
+impl_stable_hash_for!(struct hir::check_attr::ItemAttrChecks {
+    results,
+    crate_singletons,
+    unexported_used_statics,
+    referenced_statics
+});
+
 impl_stable_hash_for!(struct hir::Freevar {
     def,
     span
@@ -349,7 +349,8 @@ impl_stable_hash_for!(struct ty::VariantDef {
     name,
     discr,
     fields,
-    ctor_kind
+    ctor_kind,
+    non_exhaustive
 });
 
 impl_stable_hash_for!(enum ty::VariantDiscr {
@@ -1023,6 +1024,7 @@ impl_stable_hash_for!(struct ty::TraitDef {
     unsafety,
     paren_sugar,
     has_auto_impl,
+    is_marker,
     def_path_hash,
 });
 
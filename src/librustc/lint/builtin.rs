@@ -329,6 +329,108 @@ declare_lint! {
      via the module system"
 }
 
+declare_lint! {
+    pub NO_MANGLE_GENERIC_ITEMS,
+    Warn,
+    "generic items must be mangled"
+}
+
+declare_lint! {
+    pub USED_WITHOUT_LINK_SECTION,
+    Warn,
+    "detects `#[used]` `#[no_mangle]` statics that are not placed in a section"
+}
+
+declare_lint! {
+    pub UNREFERENCED_USED_STATICS,
+    Warn,
+    "detects `#[used]` statics that are never referenced and not exported or placed in a section"
+}
+
+declare_lint! {
+    pub ZERO_SIZED_REPR_C,
+    Allow,
+    "detects `#[repr(C)]` structs and unions without any fields"
+}
+
+declare_lint! {
+    pub REPR_C_DATA_ENUMS_WITH_INT,
+    Allow,
+    "detects enums with fields which have both a `C` and a primitive representation"
+}
+
+declare_lint! {
+    pub INLINE_ALWAYS_IN_NO_BUILTINS,
+    Warn,
+    "detects `#[inline(always)]` functions in `#![no_builtins]` crates"
+}
+
+declare_lint! {
+    pub INLINE_AND_COLD,
+    Warn,
+    "detects functions marked both `#[inline]` and `#[cold]`"
+}
+
+declare_lint! {
+    pub INLINE_ALWAYS_EXPORTED,
+    Warn,
+    "detects `#[inline(always)]` functions exported with `#[no_mangle]` or `#[export_name]`"
+}
+
+declare_lint! {
+    pub NO_MANGLE_STATIC_MUT,
+    Warn,
+    "detects `#[no_mangle]` statics that are `mut`"
+}
+
+declare_lint! {
+    pub INLINE_ON_REQUIRED_TRAIT_METHODS,
+    Warn,
+    "detects `#[inline]` on trait methods without a default body"
+}
+
+declare_lint! {
+    pub PACKED_DERIVE_REFERENCES,
+    Warn,
+    "detects derives on `#[repr(packed)]` structs that take references to their fields"
+}
+
+declare_lint! {
+    pub MULTIPLE_REPR_ATTRIBUTES,
+    Allow,
+    "detects representation hints spread across several `#[repr]` attributes"
+}
+
+declare_lint! {
+    pub NON_EXHAUSTIVE_STABLE_REPR,
+    Warn,
+    "detects `#[non_exhaustive]` types with a `C` or primitive representation"
+}
+
+declare_lint! {
+    pub NON_EXHAUSTIVE_UNIT_STRUCTS,
+    Warn,
+    "detects `#[non_exhaustive]` on unit structs, which have no fields to withhold"
+}
+
+declare_lint! {
+    pub CONFLICTING_REPR_HINTS,
+    Warn,
+    "detects representation hints that conflict with each other"
+}
+
+declare_lint! {
+    pub MISPLACED_INERT_ATTRIBUTES,
+    Warn,
+    "detects attributes applied to a target on which they have no effect"
+}
+
+declare_lint! {
+    pub INVALID_SYMBOL_NAMES,
+    Warn,
+    "detects symbol and section names the assembler or linker is likely to reject"
+}
+
 /// Does nothing as a lint pass, but registers some `Lint`s
 /// which are used by other parts of the compiler.
 #[derive(Copy, Clone)]
@@ -387,6 +489,23 @@ impl LintPass for HardwiredLints {
             WHERE_CLAUSES_OBJECT_SAFETY,
             PROC_MACRO_DERIVE_RESOLUTION_FALLBACK,
             MACRO_USE_EXTERN_CRATE,
+            NO_MANGLE_GENERIC_ITEMS,
+            ZERO_SIZED_REPR_C,
+            USED_WITHOUT_LINK_SECTION,
+            UNREFERENCED_USED_STATICS,
+            NON_EXHAUSTIVE_UNIT_STRUCTS,
+            REPR_C_DATA_ENUMS_WITH_INT,
+            INLINE_ALWAYS_IN_NO_BUILTINS,
+            INLINE_AND_COLD,
+            INLINE_ALWAYS_EXPORTED,
+            NO_MANGLE_STATIC_MUT,
+            INLINE_ON_REQUIRED_TRAIT_METHODS,
+            PACKED_DERIVE_REFERENCES,
+            MULTIPLE_REPR_ATTRIBUTES,
+            NON_EXHAUSTIVE_STABLE_REPR,
+            MISPLACED_INERT_ATTRIBUTES,
+            CONFLICTING_REPR_HINTS,
+            INVALID_SYMBOL_NAMES,
         )
     }
 }
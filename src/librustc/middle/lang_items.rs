@@ -304,6 +304,7 @@ language_item_table! {
     PanicFnLangItem,                 "panic",                   panic_fn;
     PanicBoundsCheckFnLangItem,      "panic_bounds_check",      panic_bounds_check_fn;
     PanicInfoLangItem,               "panic_info",              panic_info;
+    PanicLocationLangItem,           "panic_location",          panic_location;
     PanicImplLangItem,               "panic_impl",              panic_impl;
 
     ExchangeMallocFnLangItem,        "exchange_malloc",         exchange_malloc_fn;
@@ -311,6 +312,7 @@ language_item_table! {
     DropInPlaceFnLangItem,           "drop_in_place",           drop_in_place_fn;
     OomLangItem,                     "oom",                     oom;
     AllocLayoutLangItem,             "alloc_layout",            alloc_layout;
+    GlobalAllocTraitLangItem,        "global_alloc",            global_alloc_trait;
 
     StartFnLangItem,                 "start",                   start_fn;
 
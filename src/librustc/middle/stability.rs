@@ -224,7 +224,17 @@ impl<'a, 'tcx: 'a> Annotator<'a, 'tcx> {
 
             if let Some(depr) = attr::find_deprecation(self.tcx.sess.diagnostic(), attrs, item_sp) {
                 if kind == AnnotationKind::Prohibited {
-                    self.tcx.sess.span_err(item_sp, "This deprecation annotation is useless");
+                    // Uses of a trait impl or of its items are checked against the trait
+                    // and its items, so the attribute would never be looked at.
+                    let attr_sp = attrs.iter()
+                        .find(|attr| attr.check_name("deprecated"))
+                        .map_or(item_sp, |attr| attr.span);
+                    self.tcx.sess.struct_span_err(attr_sp, "this `#[deprecated]` attribute \
+                                                            has no effect")
+                        .span_label(item_sp, "trait implementations and their items \
+                                              cannot be deprecated on their own")
+                        .help("deprecate the trait or the trait item instead")
+                        .emit();
                 }
 
                 // `Deprecation` is just two pointers, no need to intern it
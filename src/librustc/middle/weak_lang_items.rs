@@ -15,6 +15,7 @@ use middle::lang_items;
 
 use rustc_target::spec::PanicStrategy;
 use syntax::ast;
+use syntax::attr;
 use syntax::symbol::Symbol;
 use syntax_pos::Span;
 use hir::def_id::DefId;
@@ -83,6 +84,13 @@ pub fn whitelisted(tcx: TyCtxt, lang_item: lang_items::LangItem) -> bool {
     false
 }
 
+/// Whether the crate is a `#![no_std]` binary, which has to provide the weak
+/// lang items `std` would otherwise provide, like the panic implementation.
+pub fn is_no_std_executable(tcx: TyCtxt) -> bool {
+    attr::contains_name(&tcx.hir.krate().attrs, "no_std") &&
+        tcx.sess.crate_types.borrow().contains(&config::CrateTypeExecutable)
+}
+
 fn verify<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                     items: &lang_items::LanguageItems) {
     // We only need to check for the presence of weak lang items if we're
@@ -113,8 +121,12 @@ fn verify<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
            !whitelisted(tcx, lang_items::$item) &&
            items.$name().is_none() {
             if lang_items::$item == lang_items::PanicImplLangItem {
-                tcx.sess.err(&format!("`#[panic_implementation]` function required, \
-                                        but not found"));
+                // `#![no_std]` binaries have this reported, at their `#![no_std]`,
+                // during attribute checking.
+                if !is_no_std_executable(tcx) {
+                    tcx.sess.err(&format!("`#[panic_implementation]` function required, \
+                                            but not found"));
+                }
             } else if lang_items::$item == lang_items::OomLangItem {
                 tcx.sess.err(&format!("`#[alloc_error_handler]` function required, \
                                         but not found"));
@@ -11,6 +11,7 @@
 pub use self::code_stats::{CodeStats, DataTypeKind, FieldInfo};
 pub use self::code_stats::{SizeKind, TypeSizeInfo, VariantInfo};
 
+use hir::check_attr::ToolAttrTargets;
 use hir::def_id::CrateNum;
 use ich::Fingerprint;
 
@@ -92,6 +93,8 @@ pub struct Session {
     pub one_time_diagnostics: Lock<FxHashSet<(DiagnosticMessageId, Option<Span>, String)>>,
     pub plugin_llvm_passes: OneThread<RefCell<Vec<String>>>,
     pub plugin_attributes: OneThread<RefCell<Vec<(String, AttributeType)>>>,
+    /// The targets plugins allow attributes of a tool to be applied to.
+    pub plugin_attribute_targets: OneThread<RefCell<Vec<ToolAttrTargets>>>,
     pub crate_types: Once<Vec<config::CrateType>>,
     pub dependency_formats: Once<dependency_format::Dependencies>,
     /// The crate_disambiguator is constructed out of all the `-C metadata`
@@ -1112,6 +1115,7 @@ pub fn build_session_(
         one_time_diagnostics: Lock::new(FxHashSet()),
         plugin_llvm_passes: OneThread::new(RefCell::new(Vec::new())),
         plugin_attributes: OneThread::new(RefCell::new(Vec::new())),
+        plugin_attribute_targets: OneThread::new(RefCell::new(Vec::new())),
         crate_types: Once::new(),
         dependency_formats: Once::new(),
         crate_disambiguator: Once::new(),
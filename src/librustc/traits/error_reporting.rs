@@ -45,6 +45,7 @@ use ty::SubtypePredicate;
 use util::nodemap::{FxHashMap, FxHashSet};
 
 use syntax_pos::{DUMMY_SP, Span};
+use syntax_pos::hygiene::{ExpnFormat, ExpnInfo};
 
 impl<'a, 'gcx, 'tcx> InferCtxt<'a, 'gcx, 'tcx> {
     pub fn report_fulfillment_errors(&self,
@@ -579,6 +580,16 @@ impl<'a, 'gcx, 'tcx> InferCtxt<'a, 'gcx, 'tcx> {
                         if self.tcx.sess.has_errors() && trait_predicate.references_error() {
                             return;
                         }
+                        // A `#[global_allocator]` static whose type is not an allocator
+                        // is reported by attribute checking, at the type of the static
+                        // rather than in each of the shims generated for it.
+                        if Some(trait_predicate.def_id()) ==
+                                self.tcx.lang_items().global_alloc_trait() &&
+                           is_global_allocator_expansion(span) {
+                            self.tcx.sess.delay_span_bug(span, "`#[global_allocator]` static \
+                                                                not checked to be an allocator");
+                            return;
+                        }
                         let trait_ref = trait_predicate.to_poly_trait_ref();
                         let (post_message, pre_message) =
                             self.get_parent_trait_ref(&obligation.cause.code)
@@ -1552,3 +1563,13 @@ impl ArgKind {
         }
     }
 }
+
+/// Whether `span` is in one of the shims generated for a `#[global_allocator]` static.
+fn is_global_allocator_expansion(span: Span) -> bool {
+    match span.ctxt().outer().expn_info() {
+        Some(ExpnInfo { format: ExpnFormat::MacroAttribute(name), .. }) => {
+            name == "global_allocator"
+        }
+        _ => false,
+    }
+}
@@ -2348,6 +2348,14 @@ impl<'a, 'gcx, 'tcx> TyCtxt<'a, 'gcx, 'tcx> {
         self.mk_imm_ref(self.types.re_static, self.mk_str())
     }
 
+    /// The type of `&'static core::panic::Location<'static>`, which
+    /// `#[track_caller]` functions receive as an implicit argument.
+    pub fn caller_location_ty(self) -> Ty<'tcx> {
+        let def_id = self.require_lang_item(lang_items::PanicLocationLangItem);
+        let substs = self.mk_substs(iter::once(Kind::from(self.types.re_static)));
+        self.mk_imm_ref(self.types.re_static, self.type_of(def_id).subst(self, substs))
+    }
+
     pub fn mk_adt(self, def: &'tcx AdtDef, substs: &'tcx Substs<'tcx>) -> Ty<'tcx> {
         // take a copy of substs so that we own the vectors inside
         self.mk_ty(TyAdt(def, substs))
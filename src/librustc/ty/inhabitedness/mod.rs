@@ -178,6 +178,11 @@ impl<'a, 'gcx, 'tcx> VariantDef {
                     f.uninhabited_from(visited, tcx, substs, false)
                 }))
             },
+            // Other crates don't get to rely on the fields of a non-exhaustive
+            // variant, so to them it is never visibly uninhabited.
+            AdtKind::Enum if self.non_exhaustive && !self.did.is_local() => {
+                DefIdForest::empty()
+            },
             AdtKind::Enum => {
                 DefIdForest::union(tcx, self.fields.iter().map(|f| {
                     f.uninhabited_from(visited, tcx, substs, true)
@@ -707,13 +707,44 @@ impl<'a, 'tcx> LayoutCx<'tcx, TyCtxt<'a, 'tcx, 'tcx>> {
                         }
                         size = cmp::max(size, field.size);
                     }
+                    let size = size.abi_align(align);
+
+                    // A transparent union is passed like its only non-zero-sized
+                    // field. Any value of the field's type may be stored in it,
+                    // so it has no invalid values for a niche.
+                    let mut abi = Abi::Aggregate { sized: true };
+                    let mut non_zst_fields = variants[0].iter().filter(|f| !f.is_zst());
+                    let only_field = match (non_zst_fields.next(), non_zst_fields.next()) {
+                        (Some(field), None) if def.repr.transparent() => Some(field),
+                        _ => None,
+                    };
+                    if let Some(field) = only_field {
+                        if field.size == size && field.align.abi() == align.abi() {
+                            match field.abi {
+                                Abi::Scalar(ref scalar) => {
+                                    abi = Abi::Scalar(scalar_unit(scalar.value));
+                                }
+                                Abi::ScalarPair(ref a, ref b) => {
+                                    abi = Abi::ScalarPair(scalar_unit(a.value),
+                                                          scalar_unit(b.value));
+                                }
+                                Abi::Vector { ref element, count } => {
+                                    abi = Abi::Vector {
+                                        element: scalar_unit(element.value),
+                                        count,
+                                    };
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
 
                     return Ok(tcx.intern_layout(LayoutDetails {
                         variants: Variants::Single { index: 0 },
                         fields: FieldPlacement::Union(variants[0].len()),
-                        abi: Abi::Aggregate { sized: true },
+                        abi,
                         align,
-                        size: size.abi_align(align)
+                        size,
                     }));
                 }
 
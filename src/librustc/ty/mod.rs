@@ -1676,6 +1676,12 @@ pub struct VariantDef {
     pub discr: VariantDiscr,
     pub fields: Vec<FieldDef>,
     pub ctor_kind: CtorKind,
+    /// Whether this is an enum variant marked `#[non_exhaustive]`, which may
+    /// gain fields in the future. Other crates can't create it with a struct
+    /// expression, and have to match it with `..`.
+    ///
+    /// See RFC 2008 (<https://github.com/rust-lang/rfcs/pull/2008>).
+    pub non_exhaustive: bool,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, RustcEncodable, RustcDecodable)]
@@ -2551,8 +2557,20 @@ impl<'a, 'gcx, 'tcx> TyCtxt<'a, 'gcx, 'tcx> {
     }
 
     /// Returns true if the impls are the same polarity and are implementing
-    /// a trait which contains no items
+    /// a `#[marker]` trait, or, with `overlapping_marker_traits`, a trait
+    /// which contains no items
     pub fn impls_are_allowed_to_overlap(self, def_id1: DefId, def_id2: DefId) -> bool {
+        if self.impl_polarity(def_id1) != self.impl_polarity(def_id2) {
+            return false;
+        }
+        // The impls of a `#[marker]` trait have nothing in them to choose between.
+        let is_marker_impl = |def_id: DefId| -> bool {
+            self.impl_trait_ref(def_id)
+                .map_or(false, |trait_ref| self.trait_def(trait_ref.def_id).is_marker)
+        };
+        if is_marker_impl(def_id1) && is_marker_impl(def_id2) {
+            return true;
+        }
         if !self.features().overlapping_marker_traits {
             return false;
         }
@@ -2564,9 +2582,7 @@ impl<'a, 'gcx, 'tcx> TyCtxt<'a, 'gcx, 'tcx> {
             .map_or(false, |trait_ref| {
                 self.associated_item_def_ids(trait_ref.def_id).is_empty()
             });
-        self.impl_polarity(def_id1) == self.impl_polarity(def_id2)
-            && trait1_is_empty
-            && trait2_is_empty
+        trait1_is_empty && trait2_is_empty
     }
 
     // Returns `ty::VariantDef` if `def` refers to a struct,
@@ -260,6 +260,18 @@ impl<'tcx> QueryDescription<'tcx> for queries::privacy_access_levels<'tcx> {
     }
 }
 
+impl<'tcx> QueryDescription<'tcx> for queries::check_crate_attrs<'tcx> {
+    fn describe(_: TyCtxt, _: CrateNum) -> String {
+        format!("checking attributes")
+    }
+}
+
+impl<'tcx> QueryDescription<'tcx> for queries::attr_check_results<'tcx> {
+    fn describe(_: TyCtxt, _: CrateNum) -> String {
+        format!("collecting the results of checking attributes")
+    }
+}
+
 impl<'tcx> QueryDescription<'tcx> for queries::typeck_item_bodies<'tcx> {
     fn describe(_: TyCtxt, _: CrateNum) -> String {
         format!("type-checking all item bodies")
@@ -838,6 +850,7 @@ impl_disk_cacheable_query!(borrowck, |def_id| def_id.is_local());
 impl_disk_cacheable_query!(mir_borrowck, |def_id| def_id.is_local());
 impl_disk_cacheable_query!(mir_const_qualif, |def_id| def_id.is_local());
 impl_disk_cacheable_query!(check_match, |def_id| def_id.is_local());
+impl_disk_cacheable_query!(check_mod_attrs, |def_id| def_id.is_local());
 impl_disk_cacheable_query!(def_symbol_name, |_| true);
 impl_disk_cacheable_query!(type_of, |def_id| def_id.is_local());
 impl_disk_cacheable_query!(predicates_of, |def_id| def_id.is_local());
@@ -13,6 +13,7 @@ use errors::DiagnosticBuilder;
 use hir::def_id::{CrateNum, DefId, DefIndex};
 use hir::def::{Def, Export};
 use hir::{self, TraitCandidate, ItemLocalId, CodegenFnAttrs};
+use hir::check_attr::{AttrCheckResult, ItemAttrChecks};
 use hir::svh::Svh;
 use infer::canonical::{self, Canonical};
 use lint;
@@ -268,6 +269,19 @@ define_queries! { <'tcx>
     /// Performs the privacy check and computes "access levels".
     [] fn privacy_access_levels: PrivacyAccessLevels(CrateNum) -> Lrc<AccessLevels>,
 
+    /// Checks that attributes are attached to targets that support them,
+    /// returning the misplaced attributes reported by the checks spanning the
+    /// whole crate. Those of each module are in its `check_mod_attrs`.
+    [] fn check_crate_attrs: CheckCrateAttrs(CrateNum) -> Lrc<Vec<AttrCheckResult>>,
+
+    /// Every misplaced attribute that was reported, for tools driving the
+    /// compiler. Only computed on demand.
+    [] fn attr_check_results: AttrCheckResults(CrateNum) -> Lrc<Vec<AttrCheckResult>>,
+
+    /// Checks the attributes of the items of a single module, and of everything
+    /// in their bodies, but not those of the modules it contains.
+    [] fn check_mod_attrs: CheckModAttrs(DefId) -> Lrc<ItemAttrChecks>,
+
     [] fn reachable_set: reachability_dep_node(CrateNum) -> ReachableSet,
 
     /// Per-body `region::ScopeTree`. The `DefId` should be the owner-def-id for the body;
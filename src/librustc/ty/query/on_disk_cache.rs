@@ -228,6 +228,7 @@ impl<'sess> OnDiskCache<'sess> {
                 encode_query_results::<const_is_rvalue_promotable_to_static, _>(tcx, enc, qri)?;
                 encode_query_results::<symbol_name, _>(tcx, enc, qri)?;
                 encode_query_results::<check_match, _>(tcx, enc, qri)?;
+                encode_query_results::<check_mod_attrs, _>(tcx, enc, qri)?;
                 encode_query_results::<codegen_fn_attrs, _>(tcx, enc, qri)?;
                 encode_query_results::<specialization_graph_of, _>(tcx, enc, qri)?;
 
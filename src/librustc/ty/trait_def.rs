@@ -36,6 +36,10 @@ pub struct TraitDef {
 
     pub has_auto_impl: bool,
 
+    /// If `true`, then this trait has the `#[marker]` attribute, indicating
+    /// that it has no associated items and that its impls may overlap.
+    pub is_marker: bool,
+
     /// The ICH of this trait's DefPath, cached here so it doesn't have to be
     /// recomputed all the time.
     pub def_path_hash: DefPathHash,
@@ -52,6 +56,7 @@ impl<'a, 'gcx, 'tcx> TraitDef {
                unsafety: hir::Unsafety,
                paren_sugar: bool,
                has_auto_impl: bool,
+               is_marker: bool,
                def_path_hash: DefPathHash)
                -> TraitDef {
         TraitDef {
@@ -59,6 +64,7 @@ impl<'a, 'gcx, 'tcx> TraitDef {
             paren_sugar,
             unsafety,
             has_auto_impl,
+            is_marker,
             def_path_hash,
         }
     }
@@ -73,13 +73,10 @@ impl<'a> Folder for ExpandAllocatorDirectives<'a> {
         } else {
             return fold::noop_fold_item(item, self);
         };
+        // Allocators that are not statics are reported during attribute checking.
         match item.node {
             ItemKind::Static(..) => {}
-            _ => {
-                self.handler
-                    .span_err(item.span, "allocators must be statics");
-                return SmallVector::one(item);
-            }
+            _ => return SmallVector::one(item),
         }
 
         if self.in_submod > 0 {
@@ -88,9 +85,8 @@ impl<'a> Folder for ExpandAllocatorDirectives<'a> {
             return SmallVector::one(item);
         }
 
+        // Further allocators are reported during attribute checking.
         if self.found {
-            self.handler
-                .span_err(item.span, "cannot define more than one #[global_allocator]");
             return SmallVector::one(item);
         }
         self.found = true;
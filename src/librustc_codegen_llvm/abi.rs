@@ -11,7 +11,7 @@
 use llvm::{self, ValueRef, AttributePlace};
 use base;
 use builder::{Builder, MemFlags};
-use common::{ty_fn_sig, C_usize};
+use common::{ty_fn_implicit_args, ty_fn_sig, C_usize};
 use context::CodegenCx;
 use mir::place::PlaceRef;
 use mir::operand::OperandValue;
@@ -282,7 +282,7 @@ impl<'a, 'tcx> FnTypeExt<'a, 'tcx> for FnType<'tcx, Ty<'tcx>> {
         let fn_ty = instance.ty(cx.tcx);
         let sig = ty_fn_sig(cx, fn_ty);
         let sig = cx.tcx.normalize_erasing_late_bound_regions(ty::ParamEnv::reveal_all(), &sig);
-        FnType::new(cx, sig, &[])
+        FnType::new(cx, sig, &ty_fn_implicit_args(cx, fn_ty))
     }
 
     fn new(cx: &CodegenCx<'a, 'tcx>,
@@ -363,7 +363,9 @@ impl<'a, 'tcx> FnTypeExt<'a, 'tcx> for FnType<'tcx, Ty<'tcx>> {
                 }
             }
         } else {
-            assert!(sig.variadic || extra_args.is_empty());
+            // Other than variadic arguments, the only extra arguments are the
+            // implicit ones of a Rust function, like a `#[track_caller]` location.
+            assert!(sig.variadic || sig.abi == Rust || extra_args.is_empty());
             extra_args
         };
 
@@ -14,17 +14,16 @@
 //! and methods are represented as just a fn ptr and not a full
 //! closure.
 
+use abi::{FnType, FnTypeExt};
 use attributes;
 use common::{self, CodegenCx};
 use consts;
 use declare;
 use llvm::{self, ValueRef};
 use monomorphize::Instance;
-use type_of::LayoutLlvmExt;
 
 use rustc::hir::def_id::DefId;
 use rustc::ty::{self, TypeFoldable};
-use rustc::ty::layout::LayoutOf;
 use rustc::ty::subst::Substs;
 
 /// Codegens a reference to a fn/method item, monomorphizing and
@@ -54,9 +53,12 @@ pub fn get_fn<'a, 'tcx>(cx: &CodegenCx<'a, 'tcx>,
     let sym = tcx.symbol_name(instance).as_str();
     debug!("get_fn({:?}: {:?}) => {}", instance, fn_ty, sym);
 
-    // Create a fn pointer with the substituted signature.
-    let fn_ptr_ty = tcx.mk_fn_ptr(common::ty_fn_sig(cx, fn_ty));
-    let llptrty = cx.layout_of(fn_ptr_ty).llvm_type(cx);
+    // Create a fn pointer with the substituted signature, taking any implicit
+    // arguments a call passes after the declared ones.
+    let sig = common::ty_fn_sig(cx, fn_ty);
+    let sig = tcx.normalize_erasing_late_bound_regions(ty::ParamEnv::reveal_all(), &sig);
+    let fn_type = FnType::new(cx, sig, &common::ty_fn_implicit_args(cx, fn_ty));
+    let llptrty = fn_type.llvm_type(cx).ptr_to();
 
     let llfn = if let Some(llfn) = declare::get_declared_value(cx, &sym) {
         // This is subtle and surprising, but sometimes we have to bitcast
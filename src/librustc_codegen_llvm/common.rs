@@ -28,6 +28,7 @@ use value::Value;
 use rustc::ty::{self, Ty, TyCtxt};
 use rustc::ty::layout::{HasDataLayout, LayoutOf};
 use rustc::hir;
+use rustc::hir::CodegenFnAttrFlags;
 
 use libc::{c_uint, c_char};
 use std::iter;
@@ -400,6 +401,22 @@ pub fn shift_mask_val<'a, 'tcx>(
     }
 }
 
+/// The arguments a call to a function of type `ty` passes after the ones in
+/// its signature, which is only ever the caller location of a
+/// `#[track_caller]` function.
+pub fn ty_fn_implicit_args<'a, 'tcx>(cx: &CodegenCx<'a, 'tcx>,
+                                     ty: Ty<'tcx>)
+                                     -> Vec<Ty<'tcx>>
+{
+    match ty.sty {
+        ty::TyFnDef(def_id, _) if cx.tcx.codegen_fn_attrs(def_id).flags
+                                        .contains(CodegenFnAttrFlags::TRACK_CALLER) => {
+            vec![cx.tcx.caller_location_ty()]
+        }
+        _ => vec![],
+    }
+}
+
 pub fn ty_fn_sig<'a, 'tcx>(cx: &CodegenCx<'a, 'tcx>,
                            ty: Ty<'tcx>)
                            -> ty::PolyFnSig<'tcx>
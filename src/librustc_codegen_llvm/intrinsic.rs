@@ -178,6 +178,10 @@ pub fn codegen_intrinsic_call<'a, 'tcx>(bx: &Builder<'a, 'tcx>,
         "type_id" => {
             C_u64(cx, cx.tcx.type_id_hash(substs.type_at(0)))
         }
+        "caller_location" => {
+            // Passed in by `codegen_terminator`, as only it knows the caller.
+            args[0].immediate()
+        }
         "init" => {
             let ty = substs.type_at(0);
             if !cx.layout_of(ty).is_zst() {
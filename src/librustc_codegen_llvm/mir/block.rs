@@ -26,7 +26,7 @@ use type_of::LayoutLlvmExt;
 use type_::Type;
 
 use syntax::symbol::Symbol;
-use syntax_pos::Pos;
+use syntax_pos::{Pos, Span};
 
 use super::{FunctionCx, LocalRef};
 use super::place::PlaceRef;
@@ -454,11 +454,12 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
                     return;
                 }
 
+                let implicit_args = common::ty_fn_implicit_args(bx.cx, callee.layout.ty);
                 let extra_args = &args[sig.inputs().len()..];
                 let extra_args = extra_args.iter().map(|op_arg| {
                     let op_ty = op_arg.ty(self.mir, bx.tcx());
                     self.monomorphize(&op_ty)
-                }).collect::<Vec<_>>();
+                }).chain(implicit_args.iter().cloned()).collect::<Vec<_>>();
 
                 let fn_ty = match def {
                     Some(ty::InstanceDef::Virtual(..)) => {
@@ -500,7 +501,7 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
                             bug!("Cannot use direct operand with an intrinsic call")
                     };
 
-                    let args: Vec<_> = args.iter().enumerate().map(|(i, arg)| {
+                    let mut args: Vec<_> = args.iter().enumerate().map(|(i, arg)| {
                         // The indices passed to simd_shuffle* in the
                         // third argument must be constant. This is
                         // checked by const-qualification, which also
@@ -527,6 +528,11 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
                         self.codegen_operand(&bx, arg)
                     }).collect();
 
+                    // The location `caller_location` returns is passed to it
+                    // the way it is to a `#[track_caller]` function.
+                    if intrinsic == Some("caller_location") {
+                        args.push(self.get_caller_location(&bx, span));
+                    }
 
                     let callee_ty = instance.as_ref().unwrap().ty(bx.cx.tcx);
                     codegen_intrinsic_call(&bx, callee_ty, &fn_ty, &args, dest,
@@ -582,6 +588,10 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
                     self.codegen_arguments_untupled(&bx, tup, &mut llargs,
                         &fn_ty.args[first_args.len()..])
                 }
+                if !implicit_args.is_empty() {
+                    let location = self.get_caller_location(&bx, span);
+                    self.codegen_argument(&bx, location, &mut llargs, fn_ty.args.last().unwrap());
+                }
 
                 let fn_ptr = match (llfn, instance) {
                     (Some(llfn), _) => llfn,
@@ -702,6 +712,29 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
         }
     }
 
+    /// The location of a call at `span`, as reported by `Location::caller()`.
+    /// Within a `#[track_caller]` function, that is its own caller's location.
+    fn get_caller_location(&mut self, bx: &Builder<'a, 'tcx>, span: Span) -> OperandRef<'tcx> {
+        if let Some(location) = self.caller_location {
+            return location;
+        }
+
+        let loc = bx.sess().codemap().lookup_char_pos(span.lo());
+        let filename = Symbol::intern(&loc.file.name.to_string()).as_str();
+        let filename = C_str_slice(bx.cx, filename);
+        let line = C_u32(bx.cx, loc.line as u32);
+        let col = C_u32(bx.cx, loc.col.to_usize() as u32 + 1);
+        let location = C_struct(bx.cx, &[filename, line, col], false);
+
+        let layout = bx.cx.layout_of(bx.tcx().caller_location_ty());
+        let align = bx.cx.align_of(layout.ty.builtin_deref(true).unwrap().ty);
+        let location = consts::addr_of(bx.cx, location, align, "caller_location");
+        OperandRef {
+            val: Immediate(consts::ptrcast(location, layout.immediate_llvm_type(bx.cx))),
+            layout,
+        }
+    }
+
     fn get_personality_slot(&mut self, bx: &Builder<'a, 'tcx>) -> PlaceRef<'tcx> {
         let cx = bx.cx;
         if let Some(slot) = self.personality_slot {
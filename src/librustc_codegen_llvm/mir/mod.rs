@@ -8,7 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use common::{C_i32, C_null};
+use common::{self, C_i32, C_null};
 use libc::c_uint;
 use llvm::{self, ValueRef, BasicBlockRef};
 use llvm::debuginfo::DIScope;
@@ -103,6 +103,10 @@ pub struct FunctionCx<'a, 'tcx:'a> {
 
     /// If this function is being monomorphized, this contains the type substitutions used.
     param_substs: &'tcx Substs<'tcx>,
+
+    /// The location of the call site, if this is a `#[track_caller]` function
+    /// and was passed it as an implicit last argument.
+    caller_location: Option<OperandRef<'tcx>>,
 }
 
 impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
@@ -204,7 +208,8 @@ pub fn codegen_mir<'a, 'tcx: 'a>(
     instance: Instance<'tcx>,
     sig: ty::FnSig<'tcx>,
 ) {
-    let fn_ty = FnType::new(cx, sig, &[]);
+    let implicit_args = common::ty_fn_implicit_args(cx, instance.ty(cx.tcx));
+    let fn_ty = FnType::new(cx, sig, &implicit_args);
     debug!("fn_ty: {:?}", fn_ty);
     let debug_context =
         debuginfo::create_function_debug_context(cx, instance, sig, llfn, mir);
@@ -250,6 +255,7 @@ pub fn codegen_mir<'a, 'tcx: 'a>(
             assert!(!instance.substs.needs_infer());
             instance.substs
         },
+        caller_location: None,
     };
 
     let memory_locals = analyze::non_ssa_locals(&fx);
@@ -311,6 +317,13 @@ pub fn codegen_mir<'a, 'tcx: 'a>(
             .collect()
     };
 
+    // The caller location is passed after all the arguments MIR knows about.
+    if !implicit_args.is_empty() {
+        let llarg = llvm::get_param(llfn, unsafe { llvm::LLVMCountParams(llfn) } - 1);
+        let layout = fx.fn_ty.args.last().unwrap().layout;
+        fx.caller_location = Some(OperandRef { val: OperandValue::Immediate(llarg), layout });
+    }
+
     // Branch to the START block, if it's not the entry block.
     if reentrant_start_block {
         bx.br(fx.blocks[mir::START_BLOCK]);
@@ -896,6 +896,7 @@ where
         lint_groups,
         llvm_passes,
         attributes,
+        attribute_targets,
         ..
     } = registry;
 
@@ -914,6 +915,7 @@ where
 
         *sess.plugin_llvm_passes.borrow_mut() = llvm_passes;
         *sess.plugin_attributes.borrow_mut() = attributes.clone();
+        *sess.plugin_attribute_targets.borrow_mut() = attribute_targets;
     })?;
 
     // Lint plugins are registered; now we can process command line flags.
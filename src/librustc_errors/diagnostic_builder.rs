@@ -183,6 +183,12 @@ impl<'a> DiagnosticBuilder<'a> {
         msg: &str,
         suggestion: Vec<(Span, String)>
     ) -> &mut Self);
+    forward!(pub fn multipart_suggestion_with_applicability(
+        &mut self,
+        msg: &str,
+        suggestion: Vec<(Span, String)>,
+        applicability: Applicability
+    ) -> &mut Self);
     forward!(pub fn span_suggestion(&mut self,
                                     sp: Span,
                                     msg: &str,
@@ -50,7 +50,7 @@ use syntax_pos::{BytePos, Span, SyntaxContext};
 use syntax::symbol::keywords;
 use syntax::errors::{Applicability, DiagnosticBuilder};
 
-use rustc::hir::{self, GenericParamKind, PatKind};
+use rustc::hir::{self, PatKind};
 use rustc::hir::intravisit::FnKind;
 
 use bad_style::{MethodLateContext, method_context};
@@ -717,6 +717,10 @@ impl EarlyLintPass for BadRepr {
                         }
                     };
                     warn
+                } else if list.is_some() {
+                    // `#[repr()]` is reported by the attribute checker along with
+                    // the rest of the hints.
+                    return;
                 } else {
                     let mut warn = cx.struct_span_lint(
                         BAD_REPR,
@@ -1162,12 +1166,6 @@ declare_lint! {
     "const items will not have their symbols exported"
 }
 
-declare_lint! {
-    NO_MANGLE_GENERIC_ITEMS,
-    Warn,
-    "generic items must be mangled"
-}
-
 #[derive(Copy, Clone)]
 pub struct InvalidNoMangleItems;
 
@@ -1175,8 +1173,7 @@ impl LintPass for InvalidNoMangleItems {
     fn get_lints(&self) -> LintArray {
         lint_array!(PRIVATE_NO_MANGLE_FNS,
                     PRIVATE_NO_MANGLE_STATICS,
-                    NO_MANGLE_CONST_ITEMS,
-                    NO_MANGLE_GENERIC_ITEMS)
+                    NO_MANGLE_CONST_ITEMS)
     }
 }
 
@@ -1203,8 +1200,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for InvalidNoMangleItems {
         };
 
         match it.node {
-            hir::ItemFn(.., ref generics, _) => {
-                if let Some(no_mangle_attr) = attr::find_by_name(&it.attrs, "no_mangle") {
+            // Generic `#[no_mangle]` functions are linted by the attribute checker.
+            hir::ItemFn(..) => {
+                if attr::contains_name(&it.attrs, "no_mangle") {
                     if attr::contains_name(&it.attrs, "linkage") {
                         return;
                     }
@@ -1214,22 +1212,6 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for InvalidNoMangleItems {
                         suggest_export(&it.vis, &mut err);
                         err.emit();
                     }
-                    for param in &generics.params {
-                        match param.kind {
-                            GenericParamKind::Lifetime { .. } => {}
-                            GenericParamKind::Type { .. } => {
-                                let mut err = cx.struct_span_lint(NO_MANGLE_GENERIC_ITEMS,
-                                                                  it.span,
-                                                                  "functions generic over \
-                                                                   types must be mangled");
-                                err.span_suggestion_short(no_mangle_attr.span,
-                                                          "remove this attribute",
-                                                          "".to_owned());
-                                err.emit();
-                                break;
-                            }
-                        }
-                    }
                 }
             }
             hir::ItemStatic(..) => {
@@ -1698,7 +1680,6 @@ impl LintPass for SoftLints {
             PRIVATE_NO_MANGLE_FNS,
             PRIVATE_NO_MANGLE_STATICS,
             NO_MANGLE_CONST_ITEMS,
-            NO_MANGLE_GENERIC_ITEMS,
             MUTABLE_TRANSMUTES,
             UNSTABLE_FEATURES,
             UNIONS_WITH_DROP_FIELDS,
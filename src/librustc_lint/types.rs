@@ -539,7 +539,7 @@ impl<'a, 'tcx> ImproperCTypesVisitor<'a, 'tcx> {
                         if all_phantom { FfiPhantom(ty) } else { FfiSafe }
                     }
                     AdtKind::Union => {
-                        if !def.repr.c() {
+                        if !def.repr.c() && !def.repr.transparent() {
                             return FfiUnsafe {
                                 ty: ty,
                                 reason: "this union has unspecified layout",
@@ -561,6 +561,15 @@ impl<'a, 'tcx> ImproperCTypesVisitor<'a, 'tcx> {
                                 ParamEnv::reveal_all(),
                                 field.ty(cx, substs),
                             );
+                            if def.repr.transparent() {
+                                let is_zst = cx
+                                    .layout_of(cx.param_env(field.did).and(field_ty))
+                                    .map(|layout| layout.is_zst())
+                                    .unwrap_or(false);
+                                if is_zst {
+                                    continue;
+                                }
+                            }
                             let r = self.check_type_for_ffi(cache, field_ty);
                             match r {
                                 FfiSafe => {
@@ -583,7 +592,7 @@ impl<'a, 'tcx> ImproperCTypesVisitor<'a, 'tcx> {
 
                         // Check for a repr() attribute to specify the size of the
                         // discriminant.
-                        if !def.repr.c() && def.repr.int.is_none() {
+                        if !def.repr.c() && !def.repr.transparent() && def.repr.int.is_none() {
                             // Special-case types like `Option<extern fn()>`.
                             if !is_repr_nullable_ptr(cx, def, substs) {
                                 return FfiUnsafe {
@@ -602,6 +611,17 @@ impl<'a, 'tcx> ImproperCTypesVisitor<'a, 'tcx> {
                                     ParamEnv::reveal_all(),
                                     field.ty(cx, substs),
                                 );
+                                // The only variant of a transparent enum may have
+                                // arbitrary ZSTs, like a transparent struct.
+                                if def.repr.transparent() {
+                                    let is_zst = cx
+                                        .layout_of(cx.param_env(field.did).and(arg))
+                                        .map(|layout| layout.is_zst())
+                                        .unwrap_or(false);
+                                    if is_zst {
+                                        continue;
+                                    }
+                                }
                                 let r = self.check_type_for_ffi(cache, arg);
                                 match r {
                                     FfiSafe => {}
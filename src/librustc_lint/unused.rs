@@ -8,6 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use rustc::hir::check_attr::must_use_reason;
 use rustc::hir::def::Def;
 use rustc::hir::def_id::DefId;
 use rustc::ty;
@@ -131,20 +132,20 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedResults {
         }
 
         fn check_must_use(cx: &LateContext, def_id: DefId, sp: Span, describe_path: &str) -> bool {
-            for attr in cx.tcx.get_attrs(def_id).iter() {
-                if attr.check_name("must_use") {
-                    let mut msg = format!("unused {}`{}` which must be used",
-                                          describe_path, cx.tcx.item_path_str(def_id));
+            match must_use_reason(&cx.tcx.get_attrs(def_id)) {
+                Some(reason) => {
+                    let msg = format!("unused {}`{}` which must be used",
+                                      describe_path, cx.tcx.item_path_str(def_id));
                     let mut err = cx.struct_span_lint(UNUSED_MUST_USE, sp, &msg);
                     // check for #[must_use = "..."]
-                    if let Some(note) = attr.value_str() {
+                    if let Some(note) = reason {
                         err.note(&note.as_str());
                     }
                     err.emit();
-                    return true;
+                    true
                 }
+                None => false,
             }
-            false
         }
     }
 }
@@ -226,14 +227,30 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedAttributes {
                 .find(|&&(ref x, t)| name == &**x && AttributeType::CrateLevel == t)
                 .is_some();
             if known_crate || plugin_crate {
-                let msg = match attr.style {
+                match attr.style {
                     ast::AttrStyle::Outer => {
-                        "crate-level attribute should be an inner attribute: add an exclamation \
-                         mark: #![foo]"
+                        cx.span_lint(UNUSED_ATTRIBUTES,
+                                     attr.span,
+                                     "crate-level attribute should be an inner attribute: add \
+                                      an exclamation mark: #![foo]");
                     }
-                    ast::AttrStyle::Inner => "crate-level attribute should be in the root module",
-                };
-                cx.span_lint(UNUSED_ATTRIBUTES, attr.span, msg);
+                    ast::AttrStyle::Inner => {
+                        let mut err = cx.struct_span_lint(UNUSED_ATTRIBUTES,
+                                                          attr.span,
+                                                          "crate-level attribute should be in \
+                                                           the root module");
+                        // An inner attribute belongs to the module it is written in.
+                        let module = cx.tcx.hir.find(cx.current_lint_root());
+                        if let Some(hir::map::NodeItem(item)) = module {
+                            if let hir::ItemMod(..) = item.node {
+                                err.span_label(cx.tcx.sess.codemap().def_span(item.span),
+                                               format!("`{}` is not the crate root", item.name));
+                                err.help("move the attribute to the top of the crate root");
+                            }
+                        }
+                        err.emit();
+                    }
+                }
             }
         } else {
             debug!("Attr was used: {:?}", attr);
@@ -501,6 +501,7 @@ impl<'a, 'tcx> CrateMetadata {
                           data.unsafety,
                           data.paren_sugar,
                           data.has_auto_impl,
+                          data.is_marker,
                           self.def_path_table.def_path_hash(item_id))
     }
 
@@ -525,6 +526,7 @@ impl<'a, 'tcx> CrateMetadata {
             }).collect(),
             discr: data.discr,
             ctor_kind: data.ctor_kind,
+            non_exhaustive: data.non_exhaustive,
         }
     }
 
@@ -748,7 +750,17 @@ impl<'a, 'tcx> CrateMetadata {
                             // value namespace, they are reserved for possible future use.
                             let ctor_kind = self.get_ctor_kind(child_index);
                             let ctor_def = Def::VariantCtor(def_id, ctor_kind);
-                            let vis = self.get_visibility(child_index);
+                            let mut vis = self.get_visibility(child_index);
+                            // A variant and its constructor share an entry, so the
+                            // constructor of a non_exhaustive variant has its
+                            // visibility lowered to within its crate here.
+                            if let EntryKind::Variant(data) = child.kind {
+                                if data.decode((self, sess)).non_exhaustive &&
+                                   vis == ty::Visibility::Public {
+                                    vis = ty::Visibility::Restricted(
+                                        self.local_def_id(CRATE_DEF_INDEX));
+                                }
+                            }
                             callback(def::Export { def: ctor_def, ident, vis, span });
                         }
                         _ => {}
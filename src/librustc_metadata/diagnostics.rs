@@ -48,38 +48,6 @@ See more:
 https://doc.rust-lang.org/book/first-edition/conditional-compilation.html
 "##,
 
-E0458: r##"
-An unknown "kind" was specified for a link attribute. Erroneous code example:
-
-```ignore (cannot-test-this-because-rustdoc-stops-compile-fail-before-codegen)
-#[link(kind = "wonderful_unicorn")] extern {}
-// error: unknown kind: `wonderful_unicorn`
-```
-
-Please specify a valid "kind" value, from one of the following:
-
-* static
-* dylib
-* framework
-
-"##,
-
-E0459: r##"
-A link was used without a name parameter. Erroneous code example:
-
-```ignore (cannot-test-this-because-rustdoc-stops-compile-fail-before-codegen)
-#[link(kind = "dylib")] extern {}
-// error: #[link(...)] specified without `name = "foo"`
-```
-
-Please add the name parameter to allow the rust compiler to find the library
-you want. Example:
-
-```no_run
-#[link(kind = "dylib", name = "some_lib")] extern {} // ok!
-```
-"##,
-
 E0463: r##"
 A plugin/crate was declared but cannot be found. Erroneous code example:
 
@@ -595,7 +595,8 @@ impl<'a, 'b: 'a, 'tcx: 'b> IsolatedEncoder<'a, 'b, 'tcx> {
                 Some(self.lazy(&tcx.fn_sig(def_id)))
             } else {
                 None
-            }
+            },
+            non_exhaustive: variant.non_exhaustive,
         };
 
         let enum_id = tcx.hir.as_local_node_id(enum_did).unwrap();
@@ -721,7 +722,8 @@ impl<'a, 'b: 'a, 'tcx: 'b> IsolatedEncoder<'a, 'b, 'tcx> {
                 Some(self.lazy(&tcx.fn_sig(def_id)))
             } else {
                 None
-            }
+            },
+            non_exhaustive: variant.non_exhaustive,
         };
 
         let struct_id = tcx.hir.as_local_node_id(adt_def_id).unwrap();
@@ -1084,6 +1086,7 @@ impl<'a, 'b: 'a, 'tcx: 'b> IsolatedEncoder<'a, 'b, 'tcx> {
                     discr: variant.discr,
                     struct_ctor,
                     ctor_sig: None,
+                    non_exhaustive: variant.non_exhaustive,
                 }), repr_options)
             }
             hir::ItemUnion(..) => {
@@ -1095,6 +1098,7 @@ impl<'a, 'b: 'a, 'tcx: 'b> IsolatedEncoder<'a, 'b, 'tcx> {
                     discr: variant.discr,
                     struct_ctor: None,
                     ctor_sig: None,
+                    non_exhaustive: variant.non_exhaustive,
                 }), repr_options)
             }
             hir::ItemImpl(_, polarity, defaultness, ..) => {
@@ -1138,6 +1142,7 @@ impl<'a, 'b: 'a, 'tcx: 'b> IsolatedEncoder<'a, 'b, 'tcx> {
                     unsafety: trait_def.unsafety,
                     paren_sugar: trait_def.paren_sugar,
                     has_auto_impl: tcx.trait_is_auto(def_id),
+                    is_marker: trait_def.is_marker,
                     super_predicates: self.lazy(&tcx.super_predicates_of(def_id)),
                 };
 
@@ -69,25 +69,15 @@ impl<'a, 'tcx> ItemLikeVisitor<'tcx> for Collector<'a, 'tcx> {
                 Some("static-nobundle") => cstore::NativeStaticNobundle,
                 Some("dylib") => cstore::NativeUnknown,
                 Some("framework") => cstore::NativeFramework,
-                Some(k) => {
-                    struct_span_err!(self.tcx.sess, m.span, E0458,
-                              "unknown kind: `{}`", k)
-                        .span_label(m.span, "unknown kind").emit();
-                    cstore::NativeUnknown
-                }
-                None => cstore::NativeUnknown
+                // Unknown kinds and missing names are reported during attribute checking.
+                _ => cstore::NativeUnknown
             };
             let n = items.iter().find(|n| {
                 n.check_name("name")
             }).and_then(|a| a.value_str());
             let n = match n {
                 Some(n) => n,
-                None => {
-                    struct_span_err!(self.tcx.sess, m.span, E0459,
-                                     "#[link(...)] specified without `name = \"foo\"`")
-                        .span_label(m.span, "missing `name` argument").emit();
-                    Symbol::intern("foo")
-                }
+                None => Symbol::intern("foo"),
             };
             let cfg = items.iter().find(|k| {
                 k.check_name("cfg")
@@ -453,13 +453,17 @@ pub struct VariantData<'tcx> {
     /// If this is a tuple struct or variant
     /// ctor, this is its "function" signature.
     pub ctor_sig: Option<Lazy<ty::PolyFnSig<'tcx>>>,
+
+    /// Whether this is an enum variant marked `#[non_exhaustive]`.
+    pub non_exhaustive: bool,
 }
 
 impl_stable_hash_for!(struct VariantData<'tcx> {
     ctor_kind,
     discr,
     struct_ctor,
-    ctor_sig
+    ctor_sig,
+    non_exhaustive
 });
 
 #[derive(RustcEncodable, RustcDecodable)]
@@ -467,6 +471,7 @@ pub struct TraitData<'tcx> {
     pub unsafety: hir::Unsafety,
     pub paren_sugar: bool,
     pub has_auto_impl: bool,
+    pub is_marker: bool,
     pub super_predicates: Lazy<ty::GenericPredicates<'tcx>>,
 }
 
@@ -474,6 +479,7 @@ impl_stable_hash_for!(struct TraitData<'tcx> {
     unsafety,
     paren_sugar,
     has_auto_impl,
+    is_marker,
     super_predicates
 });
 
@@ -331,6 +331,12 @@ impl<'mir, 'tcx> super::Machine<'mir, 'tcx> for CompileTimeEvaluator {
                 };
                 ecx.write_scalar(dest, id_val, dest_layout.ty)?;
             }
+
+            "caller_location" => {
+                let span = ecx.caller_location_span();
+                let location = ecx.alloc_caller_location(span)?;
+                ecx.write_ptr(dest, location.into(), dest_layout.ty)?;
+            }
             "ctpop" | "cttz" | "cttz_nonzero" | "ctlz" | "ctlz_nonzero" | "bswap" => {
                 let ty = substs.type_at(0);
                 let layout_of = ecx.layout_of(ty)?;
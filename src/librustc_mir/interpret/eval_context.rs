@@ -2,6 +2,7 @@ use std::fmt::Write;
 use std::hash::{Hash, Hasher};
 use std::mem;
 
+use rustc::hir::CodegenFnAttrFlags;
 use rustc::hir::def_id::DefId;
 use rustc::hir::def::Def;
 use rustc::hir::map::definitions::DefPathData;
@@ -18,6 +19,7 @@ use rustc::mir::interpret::{
 };
 
 use syntax::codemap::{self, Span};
+use syntax_pos::Pos;
 use syntax::ast::Mutability;
 
 use super::{Place, PlaceExtra, Memory,
@@ -1717,6 +1719,41 @@ impl<'a, 'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> EvalContext<'a, 'mir, 'tcx, M
         Ok(())
     }
 
+    /// The span `Location::caller()` reports when called at the current span:
+    /// the call site of the outermost of the `#[track_caller]` frames on top of
+    /// the stack, if there are any.
+    pub fn caller_location_span(&self) -> Span {
+        let mut span = self.tcx.span;
+        for frame in self.stack().iter().rev() {
+            let flags = self.tcx.codegen_fn_attrs(frame.instance.def_id()).flags;
+            if !flags.contains(CodegenFnAttrFlags::TRACK_CALLER) {
+                break;
+            }
+            span = frame.span;
+        }
+        span
+    }
+
+    /// Allocate the `core::panic::Location` of `span`, in immutable memory
+    /// like that of a promoted constant.
+    pub fn alloc_caller_location(&mut self, span: Span) -> EvalResult<'tcx, Pointer> {
+        let loc = self.tcx.sess.codemap().lookup_char_pos(span.lo());
+        let file = self.str_to_value(&loc.file.name.to_string())?;
+        let line = Scalar::Bits { bits: loc.line as u128, defined: 32 };
+        let col = Scalar::Bits { bits: loc.col.to_usize() as u128 + 1, defined: 32 };
+
+        let location_ty = self.tcx.caller_location_ty().builtin_deref(true).unwrap().ty;
+        let layout = self.layout_of(location_ty)?;
+        let ptr = self.memory.allocate(layout.size, layout.align, MemoryKind::Stack)?;
+        for (i, &value) in [file, line.to_value(), col.to_value()].iter().enumerate() {
+            let field = layout.field(&self, i)?;
+            let field_ptr = ptr.offset(layout.fields.offset(i), &self)?;
+            self.write_value_to_ptr(value, field_ptr.into(), layout.align, field.ty)?;
+        }
+        self.memory.mark_static_initialized(ptr.alloc_id, Mutability::Immutable)?;
+        Ok(ptr)
+    }
+
     pub fn generate_stacktrace(&self, explicit_span: Option<Span>) -> (Vec<FrameInfo>, Span) {
         let mut last_span = None;
         let mut frames = Vec::new();
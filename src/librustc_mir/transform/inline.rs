@@ -232,6 +232,15 @@ impl<'a, 'tcx> Inliner<'a, 'tcx> {
             attr::InlineAttr::None => false,
         };
 
+        // A `#[track_caller]` function has to be called to receive the caller's
+        // location, and would report it for anything inlined into it as well.
+        if codegen_fn_attrs.flags.contains(CodegenFnAttrFlags::TRACK_CALLER) ||
+           tcx.codegen_fn_attrs(self.source.def_id).flags
+               .contains(CodegenFnAttrFlags::TRACK_CALLER) {
+            debug!("#[track_caller] present - not inlining");
+            return false;
+        }
+
         // Only inline local functions if they would be eligible for cross-crate
         // inlining. This is to ensure that the final crate doesn't have MIR that
         // reference unexported symbols
@@ -842,6 +842,7 @@ This does not pose a problem by itself because they can't be accessed directly."
                             | "size_of"
                             | "min_align_of"
                             | "type_id"
+                            | "caller_location"
                             | "bswap"
                             | "ctpop"
                             | "cttz"
@@ -10,6 +10,7 @@
 
 //! Used by plugin crates to tell `rustc` about the plugins they provide.
 
+use rustc::hir::check_attr::{Target, ToolAttrTargets};
 use rustc::lint::{EarlyLintPassObject, LateLintPassObject, LintId, Lint};
 use rustc::session::Session;
 
@@ -61,6 +62,9 @@ pub struct Registry<'a> {
     #[doc(hidden)]
     pub attributes: Vec<(String, AttributeType)>,
 
+    #[doc(hidden)]
+    pub attribute_targets: Vec<ToolAttrTargets>,
+
     whitelisted_custom_derives: Vec<ast::Name>,
 }
 
@@ -77,6 +81,7 @@ impl<'a> Registry<'a> {
             lint_groups: HashMap::new(),
             llvm_passes: vec![],
             attributes: vec![],
+            attribute_targets: vec![],
             whitelisted_custom_derives: Vec::new(),
         }
     }
@@ -191,4 +196,18 @@ impl<'a> Registry<'a> {
     pub fn register_attribute(&mut self, name: String, ty: AttributeType) {
         self.attributes.push((name, ty));
     }
+
+    /// Register the targets an attribute of a tool may be applied to.
+    ///
+    /// `path` is the whole path of the attribute, like `clippy::author`, whose
+    /// first segment has to be a tool known to `rustc`. Attributes of a tool
+    /// are otherwise never checked against what they are applied to; once
+    /// registered, applying the attribute to anything other than `targets` is
+    /// an error saying it should be applied to `applies_to`, like "a function".
+    pub fn register_attribute_targets(&mut self,
+                                      path: String,
+                                      targets: Vec<Target>,
+                                      applies_to: String) {
+        self.attribute_targets.push(ToolAttrTargets { path, targets, applies_to });
+    }
 }
@@ -596,6 +596,10 @@ pub struct TargetOptions {
     /// The minimum alignment for global symbols.
     pub min_global_align: Option<u64>,
 
+    /// The largest alignment, in bytes, `#[repr(align)]` may ask for. Without one
+    /// only the target-independent limit of 2^29 applies.
+    pub max_align: Option<u64>,
+
     /// Default number of codegen units to use in debug mode
     pub default_codegen_units: Option<u64>,
 
@@ -702,6 +706,7 @@ impl Default for TargetOptions {
             crt_static_respected: false,
             stack_probes: false,
             min_global_align: None,
+            max_align: None,
             default_codegen_units: None,
             trap_unreachable: true,
             requires_lto: false,
@@ -958,6 +963,7 @@ impl Target {
         key!(crt_static_respected, bool);
         key!(stack_probes, bool);
         key!(min_global_align, Option<u64>);
+        key!(max_align, Option<u64>);
         key!(default_codegen_units, Option<u64>);
         key!(trap_unreachable, bool);
         key!(requires_lto, bool);
@@ -1167,6 +1173,7 @@ impl ToJson for Target {
         target_option_val!(crt_static_respected);
         target_option_val!(stack_probes);
         target_option_val!(min_global_align);
+        target_option_val!(max_align);
         target_option_val!(default_codegen_units);
         target_option_val!(trap_unreachable);
         target_option_val!(requires_lto);
@@ -814,6 +814,12 @@ https://doc.rust-lang.org/reference/types.html#trait-objects");
 
         self.demand_eqtype(pat.span, expected, pat_ty);
 
+        // Require `..` if the variant has non_exhaustive attribute.
+        if variant.non_exhaustive && !variant.did.is_local() && ddpos.is_none() {
+            span_err!(tcx.sess, pat.span, E0638,
+                      "`..` required with variant marked as non-exhaustive");
+        }
+
         // Type check subpatterns.
         if subpats.len() == variant.fields.len() ||
                 subpats.len() < variant.fields.len() && ddpos.is_some() {
@@ -947,8 +953,9 @@ https://doc.rust-lang.org/reference/types.html#trait-objects");
             err.emit();
         }
 
-        // Require `..` if struct has non_exhaustive attribute.
-        if adt.is_struct() && adt.is_non_exhaustive() && !adt.did.is_local() && !etc {
+        // Require `..` if struct or variant has non_exhaustive attribute.
+        let non_exhaustive = (adt.is_struct() && adt.is_non_exhaustive()) || variant.non_exhaustive;
+        if non_exhaustive && !adt.did.is_local() && !etc {
             span_err!(tcx.sess, span, E0638,
                       "`..` required with {} marked as non-exhaustive",
                       kind_name);
@@ -151,6 +151,7 @@ pub fn check_intrinsic_type<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
 
             "type_name" => (1, Vec::new(), tcx.mk_static_str()),
             "type_id" => (1, Vec::new(), tcx.types.u64),
+            "caller_location" => (0, Vec::new(), tcx.caller_location_ty()),
             "offset" | "arith_offset" => {
               (1,
                vec![
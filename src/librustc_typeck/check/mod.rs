@@ -1259,6 +1259,7 @@ fn check_union<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
     def.destructor(tcx); // force the destructor to be evaluated
     check_representable(tcx, span, def_id);
 
+    check_transparent(tcx, span, def_id);
     check_packed(tcx, span, def_id);
 }
 
@@ -1641,21 +1642,9 @@ pub fn check_simd<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, sp: Span, def_id: DefId
 fn check_packed<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, sp: Span, def_id: DefId) {
     let repr = tcx.adt_def(def_id).repr;
     if repr.packed() {
-        for attr in tcx.get_attrs(def_id).iter() {
-            for r in attr::find_repr_attrs(tcx.sess.diagnostic(), attr) {
-                if let attr::ReprPacked(pack) = r {
-                    if pack != repr.pack {
-                        struct_span_err!(tcx.sess, sp, E0634,
-                                         "type has conflicting packed representation hints").emit();
-                    }
-                }
-            }
-        }
-        if repr.align > 0 {
-            struct_span_err!(tcx.sess, sp, E0587,
-                             "type has conflicting packed and align representation hints").emit();
-        }
-        else if check_packed_inner(tcx, def_id, &mut Vec::new()) {
+        // Conflicting packed and align hints, and conflicting packed hints, are
+        // reported during attribute checking.
+        if repr.align == 0 && check_packed_inner(tcx, def_id, &mut Vec::new()) {
             struct_span_err!(tcx.sess, sp, E0588,
                 "packed type cannot transitively contain a `[repr(align)]` type").emit();
         }
@@ -1691,6 +1680,11 @@ fn check_packed_inner<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
             // only need to pop if not early out
             stack.pop();
         }
+        ty::TyAdt(def, _) if def.is_enum() => {
+            if tcx.adt_def(def.did).repr.align > 0 {
+                return true;
+            }
+        }
         _ => ()
     }
     false
@@ -1702,8 +1696,21 @@ fn check_transparent<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, sp: Span, def_id: De
         return;
     }
 
+    // A transparent enum is laid out as its only variant. Enums without any
+    // variant are reported by `check_enum`.
+    if adt.is_enum() && adt.variants.len() != 1 {
+        if adt.variants.len() > 1 {
+            struct_span_err!(tcx.sess, sp, E0725,
+                             "transparent enum needs exactly one variant, but has {}",
+                             adt.variants.len())
+                .span_label(sp, "needs exactly one variant")
+                .emit();
+        }
+        return;
+    }
+
     // For each field, figure out if it's known to be a ZST and align(1)
-    let field_infos: Vec<_> = adt.non_enum_variant().fields.iter().map(|field| {
+    let field_infos: Vec<_> = adt.variants[0].fields.iter().map(|field| {
         let ty = field.ty(tcx, Substs::identity_for_item(tcx, field.did));
         let param_env = tcx.param_env(field.did);
         let layout = tcx.layout_of(param_env.and(ty));
@@ -1718,16 +1725,26 @@ fn check_transparent<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, sp: Span, def_id: De
     let non_zst_count = non_zst_fields.clone().count();
     if non_zst_count != 1 {
         let field_spans: Vec<_> = non_zst_fields.map(|(span, _zst, _align1)| *span).collect();
-        struct_span_err!(tcx.sess, sp, E0690,
-                         "transparent struct needs exactly one non-zero-sized field, but has {}",
-                          non_zst_count)
-        .span_note(field_spans, "non-zero-sized field")
-        .emit();
+        let mut err = struct_span_err!(tcx.sess, sp, E0690,
+                         "transparent {} needs exactly one non-zero-sized field, but has {}",
+                          adt.descr(), non_zst_count);
+        err.span_note(field_spans, "non-zero-sized field");
+        // Also list the fields that were treated as zero-sized, so that it is clear
+        // which of them (e.g. `PhantomData` markers) don't count.
+        let zst_spans: Vec<_> = field_infos.iter()
+            .filter(|(_span, zst, _align1)| *zst)
+            .map(|(span, _zst, _align1)| *span)
+            .collect();
+        if !zst_spans.is_empty() {
+            err.span_note(zst_spans, "zero-sized field, ignored for the layout");
+        }
+        err.emit();
     }
     for &(span, zst, align1) in &field_infos {
         if zst && !align1 {
             span_err!(tcx.sess, span, E0691,
-                      "zero-sized field in transparent struct has alignment larger than 1");
+                      "zero-sized field in transparent {} has alignment larger than 1",
+                      adt.descr());
         }
     }
 }
@@ -1793,6 +1810,7 @@ pub fn check_enum<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
     }
 
     check_representable(tcx, sp, def_id);
+    check_transparent(tcx, sp, def_id);
 }
 
 impl<'a, 'gcx, 'tcx> AstConv<'gcx, 'tcx> for FnCtxt<'a, 'gcx, 'tcx> {
@@ -3557,7 +3575,7 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
 
         // Prohibit struct expressions when non exhaustive flag is set.
         if let ty::TyAdt(adt, _) = struct_ty.sty {
-            if !adt.did.is_local() && adt.is_non_exhaustive() {
+            if !adt.did.is_local() && (adt.is_non_exhaustive() || variant.non_exhaustive) {
                 span_err!(self.tcx.sess, expr.span, E0639,
                           "cannot create non-exhaustive {} using struct expression",
                           adt.variant_descr());
@@ -14,6 +14,7 @@
 
 use check::FnCtxt;
 use rustc::hir;
+use rustc::hir::CodegenFnAttrFlags;
 use rustc::hir::def_id::{DefId, DefIndex};
 use rustc::hir::intravisit::{self, NestedVisitorMap, Visitor};
 use rustc::infer::InferCtxt;
@@ -432,6 +433,7 @@ impl<'cx, 'gcx, 'tcx> WritebackCx<'cx, 'gcx, 'tcx> {
         // Resolve the type of the node with id `node_id`
         let n_ty = self.fcx.node_ty(hir_id);
         let n_ty = self.resolve(&n_ty, &span);
+        self.check_reified_track_caller(span, hir_id, n_ty);
         self.write_ty_to_tables(hir_id, n_ty);
         debug!("Node {:?} has type {:?}", hir_id, n_ty);
 
@@ -444,6 +446,29 @@ impl<'cx, 'gcx, 'tcx> WritebackCx<'cx, 'gcx, 'tcx> {
         }
     }
 
+    /// A `#[track_caller]` function is passed the location of its caller as an
+    /// implicit argument, which a call through a function pointer cannot pass.
+    fn check_reified_track_caller(&self, span: Span, hir_id: hir::HirId, ty: Ty<'gcx>) {
+        let def_id = match ty.sty {
+            ty::TyFnDef(def_id, _) => def_id,
+            _ => return,
+        };
+        let reified = self.tables.adjustments().get(hir_id).map_or(false, |adjustments| {
+            adjustments.iter().any(|adjustment| match adjustment.kind {
+                Adjust::ReifyFnPointer => true,
+                _ => false,
+            })
+        });
+        if reified && self.tcx().codegen_fn_attrs(def_id).flags
+                                .contains(CodegenFnAttrFlags::TRACK_CALLER) {
+            struct_span_err!(self.tcx().sess, span, E0735,
+                             "`#[track_caller]` function cannot be coerced to a function pointer")
+                .span_label(span, "coerced to a function pointer here")
+                .note("a call through a function pointer cannot pass the location of its caller")
+                .emit();
+        }
+    }
+
     fn visit_adjustments(&mut self, span: Span, hir_id: hir::HirId) {
         let adjustment = self.fcx
             .tables
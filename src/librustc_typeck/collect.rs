@@ -48,9 +48,12 @@ use syntax::symbol::{Symbol, keywords};
 use syntax::feature_gate;
 use syntax_pos::{Span, DUMMY_SP};
 
+use errors::{Applicability, DiagnosticBuilder};
+
 use rustc::hir::{self, map as hir_map, CodegenFnAttrs, CodegenFnAttrFlags, Unsafety};
 use rustc::hir::GenericParamKind;
-use rustc::hir::intravisit::{self, Visitor, NestedVisitorMap};
+use rustc::hir::intravisit::{self, FnKind, Visitor, NestedVisitorMap};
+use rustc::hir::map::blocks::FnLikeNode;
 use rustc::hir::def::{Def, CtorKind};
 use rustc::hir::def_id::{DefId, LOCAL_CRATE};
 
@@ -514,7 +517,8 @@ fn convert_struct_variant<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                                     did: DefId,
                                     name: ast::Name,
                                     discr: ty::VariantDiscr,
-                                    def: &hir::VariantData)
+                                    def: &hir::VariantData,
+                                    non_exhaustive: bool)
                                     -> ty::VariantDef {
     let mut seen_fields: FxHashMap<ast::Ident, Span> = FxHashMap();
     let node_id = tcx.hir.as_local_node_id(did).unwrap();
@@ -544,6 +548,7 @@ fn convert_struct_variant<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         discr,
         fields,
         ctor_kind: CtorKind::from_hir(def),
+        non_exhaustive,
     }
 }
 
@@ -573,7 +578,8 @@ fn adt_def<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                 };
                 distance_from_explicit += 1;
 
-                convert_struct_variant(tcx, did, v.node.name, discr, &v.node.data)
+                let non_exhaustive = tcx.has_attr(did, "non_exhaustive");
+                convert_struct_variant(tcx, did, v.node.name, discr, &v.node.data, non_exhaustive)
             }).collect())
         }
         ItemStruct(ref def, _) => {
@@ -585,13 +591,13 @@ fn adt_def<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
             };
             (AdtKind::Struct, vec![
                 convert_struct_variant(tcx, ctor_id.unwrap_or(def_id), item.name,
-                                       ty::VariantDiscr::Relative(0), def)
+                                       ty::VariantDiscr::Relative(0), def, false)
             ])
         }
         ItemUnion(ref def, _) => {
             (AdtKind::Union, vec![
                 convert_struct_variant(tcx, def_id, item.name,
-                                       ty::VariantDiscr::Relative(0), def)
+                                       ty::VariantDiscr::Relative(0), def, false)
             ])
         }
         _ => bug!()
@@ -675,11 +681,32 @@ fn trait_def<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         err.emit();
     }
 
+    // The impls of a marker trait may overlap because there is nothing in them
+    // to choose between, which only holds as long as the trait is empty.
+    let is_marker = tcx.has_attr(def_id, "marker");
+    if let hir::ItemTrait(.., ref trait_item_refs) = item.node {
+        if is_marker {
+            for trait_item_ref in trait_item_refs {
+                let kind = match trait_item_ref.kind {
+                    hir::AssociatedItemKind::Const => "const",
+                    hir::AssociatedItemKind::Method { .. } => "method",
+                    hir::AssociatedItemKind::Type => "type",
+                };
+                struct_span_err!(tcx.sess, trait_item_ref.span, E0740,
+                                 "marker traits cannot have associated items")
+                    .span_label(trait_item_ref.span,
+                                format!("associated {} in a `#[marker]` trait", kind))
+                    .emit();
+            }
+        }
+    }
+
     let def_path_hash = tcx.def_path_hash(def_id);
     let def = ty::TraitDef::new(def_id,
                                 unsafety,
                                 paren_sugar,
                                 is_auto,
+                                is_marker,
                                 def_path_hash);
     tcx.alloc_trait_def(def)
 }
@@ -1722,14 +1749,10 @@ fn from_target_feature(
     whitelist: &FxHashMap<String, Option<String>>,
     target_features: &mut Vec<Symbol>,
 ) {
+    // Other forms of the attribute are reported during attribute checking.
     let list = match attr.meta_item_list() {
         Some(list) => list,
-        None => {
-            let msg = "#[target_feature] attribute must be of the form \
-                       #[target_feature(..)]";
-            tcx.sess.span_err(attr.span, &msg);
-            return
-        }
+        None => return,
     };
     let rust_features = tcx.features();
     for item in list {
@@ -1838,6 +1861,87 @@ fn linkage_by_name<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, def_id: DefId, name: &
     }
 }
 
+/// Finds where `unsafe` has to be inserted in the header of the function `id`,
+/// and the text to insert there: right after the visibility, before `async`
+/// and `extern`. Nothing is suggested for `const` functions and `default`
+/// methods, where `unsafe` would have to follow another keyword.
+fn unsafe_insertion_point<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, id: DefId)
+                                    -> Option<(Span, &'static str)> {
+    let node = tcx.hir.get(tcx.hir.as_local_node_id(id)?);
+    if let hir_map::NodeImplItem(item) = node {
+        if item.defaultness.is_default() {
+            return None;
+        }
+    }
+    let fn_like = FnLikeNode::from_node(node)?;
+    if fn_like.constness() == hir::Constness::Const {
+        return None;
+    }
+    let vis = match fn_like.kind() {
+        FnKind::ItemFn(_, _, _, vis, _) => Some(vis),
+        FnKind::Method(_, _, vis, _) => vis,
+        FnKind::Closure(_) => return None,
+    };
+    match vis.map(|vis| (&vis.node, vis.span)) {
+        Some((&hir::VisibilityKind::Inherited, _)) | None => {
+            Some((fn_like.span().shrink_to_lo(), "unsafe "))
+        }
+        Some((_, span)) => Some((span.shrink_to_hi(), " unsafe")),
+    }
+}
+
+/// Suggests keeping the function `id` safe by moving its body into an inner
+/// `unsafe fn` carrying the `#[target_feature]` attribute `attr`, called from
+/// an `unsafe` block. This is only offered for functions without arguments or
+/// generics, which the inner function would have to repeat.
+fn suggest_unsafe_inner_fn<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                     id: DefId,
+                                     attrs: &[ast::Attribute],
+                                     attr: &ast::Attribute,
+                                     err: &mut DiagnosticBuilder) {
+    let fn_like = match tcx.hir.as_local_node_id(id)
+                               .and_then(|node_id| FnLikeNode::from_node(tcx.hir.get(node_id))) {
+        Some(fn_like) => fn_like,
+        None => return,
+    };
+    if !fn_like.decl().inputs.is_empty() ||
+       fn_like.asyncness() == hir::IsAsync::Async ||
+       tcx.generics_of(id).count() != 0 {
+        return;
+    }
+    let codemap = tcx.sess.codemap();
+    let body_span = tcx.hir.body(fn_like.body()).value.span;
+    let (attr_snippet, body_snippet) = match (codemap.span_to_snippet(attr.span),
+                                              codemap.span_to_snippet(body_span)) {
+        (Ok(attr_snippet), Ok(body_snippet)) => (attr_snippet, body_snippet),
+        _ => return,
+    };
+    let output = match fn_like.decl().output {
+        hir::DefaultReturn(_) => String::new(),
+        hir::Return(ref ty) => format!(" -> {}", tcx.hir.node_to_pretty_string(ty.id)),
+    };
+    let indent = " ".repeat(codemap.lookup_char_pos(fn_like.span().lo()).col.to_usize());
+    // Remove the attribute together with the whitespace up to whatever follows it.
+    let next = attrs.iter()
+        .map(|attr| attr.span.lo())
+        .filter(|&lo| lo > attr.span.hi())
+        .chain(Some(fn_like.span().lo()))
+        .min()
+        .unwrap();
+    let body = format!("{{\n\
+                        {0}    {1}\n\
+                        {0}    unsafe fn inner(){2} {3}\n\
+                        {0}    unsafe {{ inner() }}\n\
+                        {0}}}",
+                       indent, attr_snippet, output, body_snippet);
+    err.multipart_suggestion_with_applicability(
+        "to keep the function safe, move its body into an `unsafe` function with the \
+         attribute, and call that once the feature is detected",
+        vec![(attr.span.with_hi(next), String::new()), (body_span, body)],
+        Applicability::MaybeIncorrect,
+    );
+}
+
 fn codegen_fn_attrs<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, id: DefId) -> CodegenFnAttrs {
     let attrs = tcx.get_attrs(id);
 
@@ -1845,7 +1949,6 @@ fn codegen_fn_attrs<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, id: DefId) -> Codegen
 
     let whitelist = tcx.target_features_whitelist(LOCAL_CRATE);
 
-    let mut inline_span = None;
     for attr in attrs.iter() {
         if attr.check_name("cold") {
             codegen_fn_attrs.flags |= CodegenFnAttrFlags::COLD;
@@ -1857,8 +1960,14 @@ fn codegen_fn_attrs<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, id: DefId) -> Codegen
             codegen_fn_attrs.flags |= CodegenFnAttrFlags::RUSTC_ALLOCATOR_NOUNWIND;
         } else if attr.check_name("naked") {
             codegen_fn_attrs.flags |= CodegenFnAttrFlags::NAKED;
+        } else if attr.check_name("track_caller") {
+            codegen_fn_attrs.flags |= CodegenFnAttrFlags::TRACK_CALLER;
         } else if attr.check_name("no_mangle") {
-            codegen_fn_attrs.flags |= CodegenFnAttrFlags::NO_MANGLE;
+            // A closure has no name to export it under, so the attribute has
+            // no effect there; attribute checking warns about it.
+            if !tcx.is_closure(id) {
+                codegen_fn_attrs.flags |= CodegenFnAttrFlags::NO_MANGLE;
+            }
         } else if attr.check_name("rustc_std_internal_symbol") {
             codegen_fn_attrs.flags |= CodegenFnAttrFlags::RUSTC_STD_INTERNAL_SYMBOL;
         } else if attr.check_name("no_debug") {
@@ -1879,7 +1988,6 @@ fn codegen_fn_attrs<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, id: DefId) -> Codegen
                     }
                     MetaItemKind::List(ref items) => {
                         mark_used(attr);
-                        inline_span = Some(attr.span);
                         if items.len() != 1 {
                             span_err!(tcx.sess.diagnostic(), attr.span, E0534,
                                         "expected one argument");
@@ -1899,26 +2007,31 @@ fn codegen_fn_attrs<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, id: DefId) -> Codegen
                 }
             });
         } else if attr.check_name("export_name") {
+            // Other forms of the attribute, and null characters, are reported
+            // during attribute checking, as is the attribute on a closure, where
+            // it has no effect.
             if let Some(s) = attr.value_str() {
-                if s.as_str().contains("\0") {
-                    // `#[export_name = ...]` will be converted to a null-terminated string,
-                    // so it may not contain any null characters.
-                    struct_span_err!(tcx.sess, attr.span, E0648,
-                                     "`export_name` may not contain null characters")
-                        .emit();
+                if !tcx.is_closure(id) {
+                    codegen_fn_attrs.export_name = Some(s);
                 }
-                codegen_fn_attrs.export_name = Some(s);
-            } else {
-                struct_span_err!(tcx.sess, attr.span, E0558,
-                                 "`export_name` attribute has invalid format")
-                    .span_label(attr.span, "did you mean #[export_name=\"*\"]?")
-                    .emit();
             }
         } else if attr.check_name("target_feature") {
-            if tcx.fn_sig(id).unsafety() == Unsafety::Normal {
-                let msg = "#[target_feature(..)] can only be applied to \
-                           `unsafe` function";
-                tcx.sess.span_err(attr.span, msg);
+            // A closure has no signature of its own, and the attribute is
+            // reported on it during attribute checking.
+            if !tcx.is_closure(id) && tcx.fn_sig(id).unsafety() == Unsafety::Normal {
+                let mut err = struct_span_err!(tcx.sess, attr.span, E0711,
+                                               "#[target_feature(..)] can only be applied to \
+                                                `unsafe` function");
+                if let Some((span, unsafe_kw)) = unsafe_insertion_point(tcx, id) {
+                    err.span_suggestion_with_applicability(
+                        span,
+                        "mark the function `unsafe` and check for the feature at call sites",
+                        unsafe_kw.to_string(),
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+                suggest_unsafe_inner_fn(tcx, id, &attrs, attr, &mut err);
+                err.emit();
             }
             from_target_feature(tcx, id, attr, &whitelist, &mut codegen_fn_attrs.target_features);
         } else if attr.check_name("linkage") {
@@ -1926,25 +2039,9 @@ fn codegen_fn_attrs<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, id: DefId) -> Codegen
                 codegen_fn_attrs.linkage = Some(linkage_by_name(tcx, id, &val.as_str()));
             }
         } else if attr.check_name("wasm_custom_section") {
-            match attr.value_str() {
-                Some(name) => codegen_fn_attrs.wasm_custom_section = Some(name),
-                None => {
-                    tcx.sess.span_err(attr.span, "must be of the form \
-                        #[wasm_custom_section = \"foo\"]");
-                }
-            }
-        }
-    }
-
-    // If a function uses #[target_feature] it can't be inlined into general
-    // purpose functions as they wouldn't have the right target features
-    // enabled. For that reason we also forbid #[inline(always)] as it can't be
-    // respected.
-    if codegen_fn_attrs.target_features.len() > 0 {
-        if codegen_fn_attrs.inline == InlineAttr::Always {
-            if let Some(span) = inline_span {
-                tcx.sess.span_err(span, "cannot use #[inline(always)] with \
-                                         #[target_feature]");
+            // Other forms of the attribute are reported during attribute checking.
+            if let Some(name) = attr.value_str() {
+                codegen_fn_attrs.wasm_custom_section = Some(name);
             }
         }
     }
@@ -3703,29 +3703,6 @@ For more information about the inline attribute, https:
 read://doc.rust-lang.org/reference.html#inline-attributes
 "##,
 
-E0558: r##"
-The `export_name` attribute was malformed.
-
-Erroneous code example:
-
-```ignore (error-emitted-at-codegen-which-cannot-be-handled-by-compile_fail)
-#[export_name] // error: `export_name` attribute has invalid format
-pub fn something() {}
-
-fn main() {}
-```
-
-The `export_name` attribute expects a string in order to determine the name of
-the exported symbol. Example:
-
-```
-#[export_name = "some_function"] // ok!
-pub fn something() {}
-
-fn main() {}
-```
-"##,
-
 E0559: r##"
 An unknown field was specified into an enum's structure variant.
 
@@ -4491,14 +4468,15 @@ match error {
 }
 ```
 
-Similarly, for structs, match with `..` to avoid this error.
+Similarly, for structs and for enum variants marked `non_exhaustive`, match
+with `..` to avoid this error.
 "##,
 
 E0639: r##"
-This error indicates that the struct or enum cannot be instantiated from
-outside of the defining crate as it has been marked as `non_exhaustive` and as
-such more fields/variants may be added in future that could cause adverse side
-effects for this code.
+This error indicates that the struct, enum or enum variant cannot be
+instantiated from outside of the defining crate as it has been marked as
+`non_exhaustive` and as such more fields/variants may be added in future that
+could cause adverse side effects for this code.
 
 It is recommended that you look for a `new` function or equivalent in the
 crate's documentation.
@@ -4545,15 +4523,6 @@ fn start(_: isize, _: *const *const u8) -> isize where (): Copy {
 ```
 "##,
 
-E0648: r##"
-`export_name` attributes may not contain null characters (`\0`).
-
-```compile_fail,E0648
-#[export_name="\0foo"] // error: `export_name` may not contain null characters
-pub fn bar() {}
-```
-"##,
-
 E0689: r##"
 This error indicates that the numeric value for the method being passed exists
 but the type of the numeric value or binding could not be identified.
@@ -4718,6 +4687,146 @@ ambiguity for some types, we disallow calling methods on raw pointers when
 the type is unknown.
 "##,
 
+E0711: r##"
+The `#[target_feature]` attribute was applied to a function which isn't marked
+`unsafe`.
+
+Erroneous code example:
+
+```compile_fail,E0711
+#![feature(target_feature)]
+
+#[target_feature(enable = "sse2")]
+fn foo() {} // error: #[target_feature(..)] can only be applied to `unsafe`
+            //        function
+```
+
+Calling a function compiled with additional target features on a CPU that
+doesn't support them is undefined behavior, so it is up to the caller to check
+that the features are available. Mark the function `unsafe` so that this
+obligation is visible at every call site:
+
+```
+#![feature(target_feature)]
+
+#[target_feature(enable = "sse2")]
+unsafe fn foo() {} // ok!
+```
+
+The `unsafe` keyword goes after `const` but before `async` and `extern`, as in
+`unsafe async fn` or `unsafe extern "C" fn`.
+
+To keep a safe function instead, move its body into an inner `unsafe` function
+with the attribute, and only call that once the feature is detected. This is
+also how a closure, which can't be `unsafe`, gets to use the feature:
+
+```ignore (x86-only)
+#![feature(target_feature)]
+
+fn foo() {
+    #[target_feature(enable = "sse2")]
+    unsafe fn inner() {}
+
+    if is_x86_feature_detected!("sse2") {
+        unsafe { inner() }
+    }
+}
+```
+"##,
+
+E0725: r##"
+An enum with the `repr(transparent)` representation hint has more than one
+variant.
+
+Erroneous code example:
+
+```compile_fail,E0725
+#![feature(transparent_enums)]
+
+#[repr(transparent)]
+enum Wrapper { // error: transparent enum needs exactly one variant, but has 2
+    A(u32),
+    B(u32),
+}
+```
+
+A transparent enum is represented exactly like the data in its only variant, so
+it can't also store which variant it is. Use a transparent struct, or a single
+variant:
+
+```
+#![feature(transparent_enums)]
+
+#[repr(transparent)]
+enum Wrapper {
+    A(u32),
+}
+```
+"##,
+
+E0735: r##"
+A `#[track_caller]` function was coerced to a function pointer.
+
+Erroneous code example:
+
+```compile_fail,E0735
+#![feature(track_caller)]
+
+#[track_caller]
+fn foo() {}
+
+fn main() {
+    let f: fn() = foo; // error!
+}
+```
+
+A `#[track_caller]` function is passed the location of its caller as an
+implicit argument, which is not part of its type. A call through a function
+pointer has no way of knowing it has to pass one. Wrap the call in a closure
+instead, which reports the location of the call inside it:
+
+```
+#![feature(track_caller)]
+
+#[track_caller]
+fn foo() {}
+
+fn main() {
+    let f: fn() = || foo();
+}
+```
+"##,
+
+E0740: r##"
+A `#[marker]` trait has an associated item.
+
+Erroneous code example:
+
+```compile_fail,E0740
+#![feature(marker_trait_attr)]
+
+#[marker]
+trait Marker {
+    const N: usize; // error!
+}
+```
+
+The impls of a marker trait are allowed to overlap, because a type either
+implements the trait or it doesn't, and nothing else depends on which of the
+impls applies. An associated item would have to be taken from one of them, so
+marker traits cannot have any:
+
+```
+#![feature(marker_trait_attr)]
+
+#[marker]
+trait Marker {}
+
+impl<T: Clone> Marker for T {}
+impl<T: Copy> Marker for T {}
+```
+"##,
+
 }
 
 register_diagnostics! {
@@ -4787,7 +4896,6 @@ register_diagnostics! {
 //  E0563, // cannot determine a type for this `impl Trait`: {} // removed in 6383de15
     E0564, // only named lifetimes are allowed in `impl Trait`,
            // but `{}` was found in the type `{}`
-    E0587, // type has conflicting packed and align representation hints
     E0588, // packed type cannot transitively contain a `[repr(align)]` type
     E0592, // duplicate definitions with name `{}`
 //  E0611, // merged into E0616
@@ -4796,7 +4904,6 @@ register_diagnostics! {
     E0627, // yield statement outside of generator literal
     E0632, // cannot provide explicit type parameters when `impl Trait` is used in
            // argument position.
-    E0634, // type has conflicting packed representaton hints
     E0640, // infer outlives requirements
     E0641, // cannot cast to/from a pointer with an unknown kind
     E0645, // trait aliases not finished
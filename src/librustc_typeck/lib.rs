@@ -226,6 +226,12 @@ fn check_main_fn_ty<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                 tcx.mk_nil()
             };
 
+            // A wrong number of arguments has already been reported during attribute
+            // checking, together with the expected signature.
+            if tcx.fn_sig(start_def_id).skip_binder().inputs().len() != 2 {
+                return;
+            }
+
             let se_ty = tcx.mk_fn_ptr(ty::Binder::bind(
                 tcx.mk_fn_sig(
                     iter::empty(),
@@ -10,7 +10,7 @@
 
 //! Parsing and validation of builtin attributes
 
-use ast::{self, Attribute, MetaItem, Name, NestedMetaItemKind};
+use ast::{self, Attribute, MetaItem, Name, NestedMetaItem, NestedMetaItemKind};
 use errors::{Applicability, Handler};
 use feature_gate::{Features, GatedCfg};
 use parse::ParseSess;
@@ -631,41 +631,29 @@ pub fn find_repr_attrs(diagnostic: &Handler, attr: &Attribute) -> Vec<ReprAttr>
                         acc.push(h);
                     }
                 } else if let Some((name, value)) = item.name_value_literal() {
-                    let parse_alignment = |node: &ast::LitKind| -> Result<u32, &'static str> {
+                    let parse_alignment = |node: &ast::LitKind| -> Option<u32> {
                         if let ast::LitKind::Int(literal, ast::LitIntType::Unsuffixed) = node {
-                            if literal.is_power_of_two() {
-                                // rustc::ty::layout::Align restricts align to <= 2^29
-                                if *literal <= 1 << 29 {
-                                    Ok(*literal as u32)
-                                } else {
-                                    Err("larger than 2^29")
-                                }
-                            } else {
-                                Err("not a power of two")
+                            // rustc::ty::layout::Align restricts align to <= 2^29
+                            if literal.is_power_of_two() && *literal <= 1 << 29 {
+                                return Some(*literal as u32);
                             }
-                        } else {
-                            Err("not an unsuffixed integer")
                         }
+                        None
                     };
 
-                    let mut literal_error = None;
+                    // Invalid alignments are reported, with the reason, by
+                    // `rustc::hir::check_attr`.
                     if name == "align" {
                         recognised = true;
-                        match parse_alignment(&value.node) {
-                            Ok(literal) => acc.push(ReprAlign(literal)),
-                            Err(message) => literal_error = Some(message)
-                        };
+                        if let Some(literal) = parse_alignment(&value.node) {
+                            acc.push(ReprAlign(literal));
+                        }
                     }
                     else if name == "packed" {
                         recognised = true;
-                        match parse_alignment(&value.node) {
-                            Ok(literal) => acc.push(ReprPacked(literal)),
-                            Err(message) => literal_error = Some(message)
-                        };
-                    }
-                    if let Some(literal_error) = literal_error {
-                        span_err!(diagnostic, item.span, E0589,
-                                  "invalid `repr(align)` attribute: {}", literal_error);
+                        if let Some(literal) = parse_alignment(&value.node) {
+                            acc.push(ReprPacked(literal));
+                        }
                     }
                 } else {
                     if let Some(meta_item) = item.meta_item() {
@@ -698,10 +686,21 @@ pub fn find_repr_attrs(diagnostic: &Handler, attr: &Attribute) -> Vec<ReprAttr>
                         }
                     }
                 }
-                if !recognised {
+                if !recognised && !is_known_repr_hint(item) {
                     // Not a word we recognize
-                    span_err!(diagnostic, item.span, E0552,
-                              "unrecognized representation hint");
+                    let mut err = struct_span_err!(diagnostic, item.span, E0552,
+                                                   "unrecognized representation hint");
+                    if let Some(hint) = item.word().and_then(|mi| {
+                        repr_hint_with_case_of(&mi.name().as_str())
+                    }) {
+                        err.span_suggestion_with_applicability(
+                            item.span,
+                            "did you mean",
+                            hint.to_string(),
+                            Applicability::MachineApplicable
+                        );
+                    }
+                    err.emit();
                 }
             }
         }
@@ -709,6 +708,25 @@ pub fn find_repr_attrs(diagnostic: &Handler, attr: &Attribute) -> Vec<ReprAttr>
     acc
 }
 
+/// Whether `item` names a known representation hint, even if it was written with the wrong
+/// arguments. Those are reported by the attribute checks in librustc.
+fn is_known_repr_hint(item: &NestedMetaItem) -> bool {
+    item.name().map_or(false, |name| match &*name.as_str() {
+        "C" | "packed" | "simd" | "transparent" | "align" => true,
+        word => int_type_of_word(word).is_some(),
+    })
+}
+
+/// The hint written without arguments, like `#[repr(c)]`, that `word` only
+/// differs from in case.
+fn repr_hint_with_case_of(word: &str) -> Option<&'static str> {
+    const WORD_HINTS: &[&str] = &[
+        "C", "packed", "simd", "transparent",
+        "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "i128", "u128", "isize", "usize",
+    ];
+    WORD_HINTS.iter().cloned().find(|hint| hint.eq_ignore_ascii_case(word))
+}
+
 fn int_type_of_word(s: &str) -> Option<IntType> {
     use self::IntType::*;
 
@@ -307,15 +307,11 @@ fn main() {
 "##,
 
 E0589: r##"
-The value of `N` that was specified for `repr(align(N))` was not a power
-of two, or was greater than 2^29.
+#### Note: this error code is no longer emitted by the compiler.
 
-```compile_fail,E0589
-#[repr(align(15))] // error: invalid `repr(align)` attribute: not a power of two
-enum Foo {
-    Bar(u64),
-}
-```
+The value of `N` that was specified for `repr(align(N))` or `repr(packed(N))`
+was not a power of two, or was greater than 2^29. This is now reported as
+E0722, E0723 or E0724.
 "##,
 
 E0658: r##"
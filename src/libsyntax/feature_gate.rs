@@ -31,10 +31,11 @@ use attr;
 use codemap::Spanned;
 use edition::{ALL_EDITIONS, Edition};
 use syntax_pos::{Span, DUMMY_SP};
-use errors::{DiagnosticBuilder, Handler, FatalError};
+use errors::{Applicability, DiagnosticBuilder, Handler, FatalError};
 use visit::{self, FnKind, Visitor};
 use parse::ParseSess;
 use symbol::{keywords, Symbol};
+use util::lev_distance::find_best_match_for_name;
 
 use std::{env, path};
 
@@ -484,6 +485,21 @@ declare_features! (
 
     // #[alloc_error_handler]
     (active, alloc_error_handler, "1.29.0", Some(51540), None),
+
+    // Allows `#[repr(transparent)]` on unions
+    (active, transparent_unions, "1.29.0", None, None),
+
+    // Allows `#[repr(align(x))]` on enums
+    (active, repr_align_enum, "1.29.0", None, None),
+
+    // Allows `#[repr(transparent)]` on univariant enums
+    (active, transparent_enums, "1.29.0", None, None),
+
+    // Allows `#[track_caller]` and `Location::caller()`
+    (active, track_caller, "1.29.0", Some(47809), None),
+
+    // Allows `#[marker]` on traits, letting their impls overlap
+    (active, marker_trait_attr, "1.29.0", Some(29864), None),
 );
 
 declare_features! (
@@ -964,6 +980,15 @@ pub const BUILTIN_ATTRIBUTES: &'static [(&'static str, AttributeType, AttributeG
                                  "the `#[naked]` attribute \
                                   is an experimental feature",
                                  cfg_fn!(naked_functions))),
+    ("track_caller", Whitelisted, Gated(Stability::Unstable,
+                                        "track_caller",
+                                        "the `#[track_caller]` attribute \
+                                         is an experimental feature",
+                                        cfg_fn!(track_caller))),
+    ("marker", Normal, Gated(Stability::Unstable,
+                             "marker_trait_attr",
+                             "marker traits is an experimental feature",
+                             cfg_fn!(marker_trait_attr))),
     ("target_feature", Whitelisted, Ungated),
     ("export_name", Whitelisted, Ungated),
     ("inline", Whitelisted, Ungated),
@@ -1226,19 +1251,43 @@ impl<'a> Context<'a> {
                             attr.path
                         );
                     }
-                } else {
-                    gate_feature!(self, custom_attribute, attr.span,
-                                  &format!("The attribute `{}` is currently \
-                                            unknown to the compiler and \
-                                            may have meaning \
-                                            added to it in the future",
-                                           attr.path));
+                } else if !self.features.custom_attribute && !attr.span.allows_unstable() {
+                    let mut err = feature_err(self.parse_sess,
+                                              "custom_attribute",
+                                              attr.span,
+                                              GateIssue::Language,
+                                              &format!("The attribute `{}` is currently \
+                                                        unknown to the compiler and \
+                                                        may have meaning \
+                                                        added to it in the future",
+                                                       attr.path));
+                    if let Some(similar) = similar_builtin_attribute(&name) {
+                        err.span_suggestion_with_applicability(
+                            attr.path.span,
+                            "a built-in attribute with a similar name exists",
+                            similar.to_string(),
+                            Applicability::MachineApplicable,
+                        );
+                    }
+                    err.emit();
                 }
             }
         }
     }
 }
 
+/// The built-in attribute whose name is closest to `name`, if any is close
+/// enough to be a likely misspelling of it. Internal attributes are never
+/// suggested.
+fn similar_builtin_attribute(name: &str) -> Option<Symbol> {
+    let names: Vec<_> = BUILTIN_ATTRIBUTES.iter()
+        .map(|&(name, ..)| name)
+        .filter(|name| !name.starts_with("rustc_") && !name.starts_with("derive_"))
+        .map(Symbol::intern)
+        .collect();
+    find_best_match_for_name(names.iter(), name, None)
+}
+
 pub fn check_attribute(attr: &ast::Attribute, parse_sess: &ParseSess, features: &Features) {
     let cx = Context { features: features, parse_sess: parse_sess, plugin_attributes: &[] };
     cx.check_attribute(attr, true);
@@ -1602,7 +1651,7 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
                 }
             }
 
-            ast::ItemKind::Struct(..) => {
+            ast::ItemKind::Struct(..) | ast::ItemKind::Union(..) => {
                 if let Some(attr) = attr::find_by_name(&i.attrs[..], "repr") {
                     for item in attr.meta_item_list().unwrap_or_else(Vec::new) {
                         if item.check_name("simd") {
@@ -260,7 +260,15 @@ impl<'a> Parser<'a> {
 
         let found = self.this_token_to_string();
         let msg = format!("expected unsuffixed literal or identifier, found {}", found);
-        Err(self.diagnostic().struct_span_err(lo, &msg))
+        // Point at the whole of a malformed group like `(x)`, not just its opening
+        // delimiter.
+        let span = match self.token {
+            token::OpenDelim(_) => self.parse_token_tree().span(),
+            _ => lo,
+        };
+        let mut err = self.diagnostic().struct_span_err(span, &msg);
+        err.span_label(span, "expected a literal, or a name optionally followed by arguments");
+        Err(err)
     }
 
     /// matches meta_seq = ( COMMASEP(meta_item_inner) )
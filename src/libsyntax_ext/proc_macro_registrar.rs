@@ -124,6 +124,20 @@ impl<'a> CollectProcMacros<'a> {
                 return
             }
         };
+        if list.is_empty() {
+            self.handler.span_err(attr.span(),
+                                  "attribute must name the trait to derive: \
+                                   #[proc_macro_derive(TraitName)]");
+            return
+        }
+        let mut attributes_lists = list.iter().skip(1).filter(|a| a.check_name("attributes"));
+        if attributes_lists.next().is_some() {
+            let duplicates: Vec<_> = attributes_lists.map(|a| a.span()).collect();
+            if !duplicates.is_empty() {
+                self.handler.span_err(duplicates, "`attributes` may only be specified once");
+                return
+            }
+        }
         if list.len() != 1 && list.len() != 2 {
             self.handler.span_err(attr.span(),
                                   "attribute must have either one or two arguments");
@@ -165,7 +179,8 @@ impl<'a> CollectProcMacros<'a> {
                 let name = match attr.name() {
                     Some(name) => name,
                     _ => {
-                        self.handler.span_err(attr.span(), "not a meta item");
+                        self.handler.span_err(attr.span(),
+                                              "helper attribute name must be an identifier");
                         return None;
                     },
                 };
@@ -19,7 +19,7 @@
 // CHECK-NEXT: define void @naked_empty()
 #[no_mangle]
 #[naked]
-pub fn naked_empty() {
+pub extern "C" fn naked_empty() {
     // CHECK-NEXT: {{.+}}:
     // CHECK-NEXT: ret void
 }
@@ -28,7 +28,7 @@ pub fn naked_empty() {
 #[no_mangle]
 #[naked]
 // CHECK-NEXT: define void @naked_with_args(i{{[0-9]+}})
-pub fn naked_with_args(a: isize) {
+pub extern "C" fn naked_with_args(a: isize) {
     // CHECK-NEXT: {{.+}}:
     // CHECK-NEXT: %a = alloca i{{[0-9]+}}
     &a; // keep variable in an alloca
@@ -39,7 +39,7 @@ pub fn naked_with_args(a: isize) {
 // CHECK-NEXT: define i{{[0-9]+}} @naked_with_return()
 #[no_mangle]
 #[naked]
-pub fn naked_with_return() -> isize {
+pub extern "C" fn naked_with_return() -> isize {
     // CHECK-NEXT: {{.+}}:
     // CHECK-NEXT: ret i{{[0-9]+}} 0
     0
@@ -49,7 +49,7 @@ pub fn naked_with_return() -> isize {
 // CHECK-NEXT: define i{{[0-9]+}} @naked_with_args_and_return(i{{[0-9]+}})
 #[no_mangle]
 #[naked]
-pub fn naked_with_args_and_return(a: isize) -> isize {
+pub extern "C" fn naked_with_args_and_return(a: isize) -> isize {
     // CHECK-NEXT: {{.+}}:
     // CHECK-NEXT: %a = alloca i{{[0-9]+}}
     &a; // keep variable in an alloca
@@ -61,7 +61,7 @@ pub fn naked_with_args_and_return(a: isize) -> isize {
 // CHECK-NEXT: define void @naked_recursive()
 #[no_mangle]
 #[naked]
-pub fn naked_recursive() {
+pub extern "C" fn naked_recursive() {
     // CHECK-NEXT: {{.+}}:
     // CHECK-NEXT: call void @naked_empty()
 
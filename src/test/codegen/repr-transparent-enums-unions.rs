@@ -0,0 +1,55 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type="lib"]
+#![feature(transparent_enums, transparent_unions, untagged_unions)]
+
+use std::marker::PhantomData;
+
+pub struct Zst;
+
+#[repr(transparent)]
+pub enum F32Enum {
+    Value(f32),
+}
+
+// CHECK: define float @test_F32Enum(float %arg0)
+#[no_mangle]
+pub extern fn test_F32Enum(_: F32Enum) -> F32Enum { loop {} }
+
+#[repr(transparent)]
+pub enum PtrEnum {
+    Value(*mut u8, Zst),
+}
+
+// CHECK: define i8* @test_PtrEnum(i8* %arg0)
+#[no_mangle]
+pub extern fn test_PtrEnum(_: PtrEnum) -> PtrEnum { loop {} }
+
+#[repr(transparent)]
+pub union F32Union {
+    value: f32,
+    marker: PhantomData<u8>,
+}
+
+// CHECK: define float @test_F32Union(float %arg0)
+#[no_mangle]
+pub extern fn test_F32Union(_: F32Union) -> F32Union { loop {} }
+
+#[repr(transparent)]
+pub union U64Union {
+    value: u64,
+}
+
+// CHECK: define i64 @test_U64Union(i64 %arg0)
+#[no_mangle]
+pub extern fn test_U64Union(_: U64Union) -> U64Union { loop {} }
@@ -0,0 +1,29 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:tool_attr_targets_plugin.rs
+// ignore-stage1
+
+#![feature(plugin, tool_attributes)]
+#![plugin(tool_attr_targets_plugin)]
+
+#[clippy::author] // OK
+fn foo() {}
+
+#[clippy::author] //~ ERROR attribute should be applied to a function
+struct Foo;
+
+// An attribute of a tool nothing was registered for is still left alone.
+#[clippy::cyclomatic_complexity = "0"]
+struct Bar;
+
+fn main() {
+    foo();
+}
@@ -9,6 +9,7 @@
 // except according to those terms.
 
 #![crate_type = "proc-macro"]
+#![feature(attr_literals)]
 
 extern crate proc_macro;
 
@@ -61,3 +62,22 @@ pub fn foo7(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn foo8(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     input
 }
+
+#[proc_macro_derive()]
+//~^ ERROR: attribute must name the trait to derive
+pub fn foo9(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    input
+}
+
+#[proc_macro_derive(o, attributes(1, 2))]
+//~^ ERROR: helper attribute name must be an identifier
+//~| ERROR: helper attribute name must be an identifier
+pub fn foo10(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    input
+}
+
+#[proc_macro_derive(p, attributes(q), attributes(r))]
+//~^ ERROR: `attributes` may only be specified once
+pub fn foo11(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    input
+}
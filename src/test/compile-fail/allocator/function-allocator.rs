@@ -9,7 +9,10 @@
 // except according to those terms.
 
 
-#[global_allocator]
-fn foo() {} //~ ERROR: allocators must be statics
+#[global_allocator] //~ ERROR: attribute should be applied to a static
+fn foo() {}
+
+#[global_allocator] //~ ERROR: attribute should be applied to a static
+const A: usize = 0;
 
 fn main() {}
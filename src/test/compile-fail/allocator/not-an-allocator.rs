@@ -10,9 +10,6 @@
 
 #[global_allocator]
 static A: usize = 0;
-//~^ the trait bound `usize:
-//~| the trait bound `usize:
-//~| the trait bound `usize:
-//~| the trait bound `usize:
+//~^ ERROR the trait bound `usize:
 
 fn main() {}
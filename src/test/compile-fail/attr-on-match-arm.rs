@@ -0,0 +1,27 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+fn main() {
+    match Some(1) {
+        #[inline]
+        //~^ ERROR attribute should be applied to function or closure
+        Some(_) => {}
+        #[repr(C)]
+        //~^ ERROR attribute should not be applied to a match arm
+        None => {}
+    }
+
+    match 0 {
+        #[cfg(any())]
+        1 => {}
+        #[cfg(all())]
+        _ => {}
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(extern_types, non_exhaustive, used)]
+
+extern {
+    #[inline] //~ ERROR attribute should be applied to function or closure
+    fn inlined();
+
+    #[used] //~ ERROR attribute should be applied to a `static` variable
+    static KEPT: u32;
+
+    #[non_exhaustive] //~ ERROR attribute should be applied to a struct, enum or enum variant
+    type Opaque;
+
+    #[link_name = "renamed"] // OK
+    fn renamed();
+}
+
+fn main() {}
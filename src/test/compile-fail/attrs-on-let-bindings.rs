@@ -0,0 +1,24 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(stmt_expr_attributes)]
+
+fn main() {
+    #[repr(C)] //~ ERROR attribute should not be applied to a `let` binding
+    let (_a, _b) = (1u8, 2u32);
+
+    #[inline] //~ ERROR attribute should be applied to function or closure
+    let _f = || 1;
+
+    let _g = #[inline] || 1; // OK: the attribute is on the closure
+
+    #[cfg(any())]
+    let _c = 3; // OK: removed by `#[cfg]`
+}
@@ -0,0 +1,24 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Attributes on statements are checked the same way whether they were written
+// directly or produced by `#[cfg_attr]`.
+
+fn main() {
+    #[cfg_attr(all(), inline)] //~ ERROR attribute should be applied to function or closure
+    let _a = 1;
+
+    #[cfg_attr(any(), inline)] // OK: not enabled
+    let _b = 2;
+
+    #[cfg_attr(all(), cfg_attr(all(), repr(C)))]
+    //~^ ERROR attribute should not be applied to a `let` binding
+    let _c = 3;
+}
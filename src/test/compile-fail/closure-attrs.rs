@@ -0,0 +1,26 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(stmt_expr_attributes)]
+#![deny(misplaced_inert_attributes)]
+
+fn main() {
+    let _a = #[no_mangle] || {};
+    //~^ ERROR attribute should be applied to a function or static
+
+    let _b = #[no_mangle] 1;
+    //~^ ERROR attribute should be applied to a function or static
+
+    let _c = #[cold] 2;
+    //~^ ERROR attribute should be applied to a function or closure
+
+    let _d = #[cold] || {}; // OK
+    let _e = #[inline] || {}; // OK
+}
@@ -0,0 +1,42 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-arm
+// ignore-aarch64
+// ignore-wasm
+// ignore-emscripten
+// ignore-mips
+// ignore-powerpc
+// ignore-powerpc64
+// ignore-powerpc64le
+// ignore-s390x
+// ignore-sparc
+// ignore-sparc64
+
+#![feature(naked_functions, target_feature)]
+
+#[naked]
+#[inline(always)] //~ ERROR conflicting codegen attributes on this function
+#[target_feature(enable = "sse2")]
+unsafe extern "C" fn all_three() {}
+
+#[inline(always)] //~ ERROR conflicting codegen attributes on this function
+#[target_feature(enable = "avx")]
+unsafe fn always_inlined() {}
+
+#[inline] // OK: only a hint
+#[target_feature(enable = "avx")]
+unsafe fn maybe_inlined() {}
+
+#[naked] // OK: not inlined
+#[inline(never)]
+unsafe extern "C" fn never_inlined() {}
+
+fn main() {}
@@ -26,42 +26,55 @@ enum D { D }
 #[repr(C, packed)]
 struct E(i32);
 
-#[repr(packed, align(8))]
-struct F(i32); //~ ERROR type has conflicting packed and align representation hints
+#[repr(packed, align(8))] //~ ERROR type has conflicting packed and align representation hints
+struct F(i32);
 
-#[repr(packed)]
+#[repr(packed)] //~ ERROR type has conflicting packed and align representation hints
 #[repr(align(8))]
-struct G(i32); //~ ERROR type has conflicting packed and align representation hints
+struct G(i32);
 
 #[repr(align(8))]
-#[repr(packed)]
-struct H(i32); //~ ERROR type has conflicting packed and align representation hints
+#[repr(packed)] //~ ERROR type has conflicting packed and align representation hints
+struct H(i32);
 
-#[repr(packed, packed(2))]
-struct I(i32); //~ ERROR type has conflicting packed representation hints
+#[repr(packed, packed(2))] //~ ERROR type has conflicting packed representation hints
+struct I(i32);
 
-#[repr(packed(2))]
+#[repr(packed(2))] //~ ERROR type has conflicting packed representation hints
 #[repr(packed)]
-struct J(i32); //~ ERROR type has conflicting packed representation hints
+struct J(i32);
 
 #[repr(packed, packed(1))]
 struct K(i32);
 
-#[repr(packed, align(8))]
-union X { //~ ERROR type has conflicting packed and align representation hints
+// Generic field types don't hide the conflict.
+#[repr(packed, align(8))] //~ ERROR type has conflicting packed and align representation hints
+struct L<T>(T);
+
+#[repr(align(8))]
+#[repr(packed)] //~ ERROR type has conflicting packed and align representation hints
+struct M<T>(i32, T);
+
+#[repr(packed, align(8))] //~ ERROR type has conflicting packed and align representation hints
+union X {
     i: i32
 }
 
-#[repr(packed)]
+#[repr(packed)] //~ ERROR type has conflicting packed and align representation hints
 #[repr(align(8))]
-union Y { //~ ERROR type has conflicting packed and align representation hints
+union Y {
     i: i32
 }
 
 #[repr(align(8))]
-#[repr(packed)]
-union Z { //~ ERROR type has conflicting packed and align representation hints
+#[repr(packed)] //~ ERROR type has conflicting packed and align representation hints
+union Z {
     i: i32
 }
 
+#[repr(packed, align(8))] //~ ERROR type has conflicting packed and align representation hints
+union N<T: Copy> {
+    t: T
+}
+
 fn main() {}
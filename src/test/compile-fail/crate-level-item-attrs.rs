@@ -0,0 +1,22 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Attributes that only apply to particular items are rejected at the crate root.
+
+#![feature(naked_functions)]
+
+#![inline] //~ ERROR `#![inline]` cannot be applied to a crate
+#![naked] //~ ERROR `#![naked]` cannot be applied to a crate
+#![used] //~ ERROR `#![used]` cannot be applied to a crate
+
+#[inline]
+fn foo() {}
+
+fn main() {}
@@ -0,0 +1,39 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// `#[deprecated]` can be placed on inherent impl items just like on free
+// items, but has no effect on the items of a trait impl.
+
+struct Foo;
+
+impl Foo {
+    #[deprecated(since = "1.0.0", note = "use `bar` instead")]
+    pub fn foo(&self) {}
+
+    #[deprecated]
+    pub const FOO: u32 = 0;
+}
+
+trait Tr {
+    #[deprecated]
+    fn deprecated_default(&self) {}
+
+    fn method(&self);
+}
+
+impl Tr for Foo {
+    #[deprecated] //~ ERROR this `#[deprecated]` attribute has no effect
+    fn deprecated_default(&self) {}
+
+    #[deprecated(since = "1.0.0")] //~ ERROR this `#[deprecated]` attribute has no effect
+    fn method(&self) {}
+}
+
+fn main() {}
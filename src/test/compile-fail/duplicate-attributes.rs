@@ -0,0 +1,30 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::alloc::System;
+
+#[inline]
+#[inline(always)] //~ WARN `#[inline]` is specified more than once
+pub fn twice() {}
+
+#[no_mangle]
+#[no_mangle] //~ WARN `#[no_mangle]` is specified more than once
+pub fn unmangled() {}
+
+#[global_allocator]
+static A: System = System;
+
+#[global_allocator] //~ ERROR cannot define more than one `#[global_allocator]`
+static B: System = System;
+
+#[global_allocator]
+static C: System = System;
+
+fn main() {}
@@ -0,0 +1,27 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[inline(always)] //~ ERROR conflicting `#[inline]` hints
+#[inline(never)]
+fn always_then_never() {}
+
+#[inline(never)]
+#[inline(always)] //~ ERROR conflicting `#[inline]` hints
+fn never_then_always() {}
+
+struct S;
+
+impl S {
+    #[inline(always)] //~ ERROR conflicting `#[inline]` hints
+    #[inline(never)]
+    fn method(&self) {}
+}
+
+fn main() {}
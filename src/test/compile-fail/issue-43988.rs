@@ -23,7 +23,7 @@ fn main() {
 
     #[repr(nothing)]
     let _x = 0;
-    //~^^ ERROR attribute should not be applied to a statement
+    //~^^ ERROR attribute should not be applied to a `let` binding
 
     #[repr(something_not_real)]
     loop {
@@ -33,7 +33,7 @@ fn main() {
 
     #[repr]
     let _y = "123";
-    //~^^ ERROR attribute should not be applied to a statement
+    //~^^ ERROR attribute should not be applied to a `let` binding
     //~| WARN `repr` attribute must have a hint
 
 
@@ -12,6 +12,6 @@
 
 #[start]
 fn start(argc: isize, argv: *const *const u8, crate_map: *const u8) -> isize {
-    //~^ start function has wrong type
+    //~^ ERROR start function takes 3 arguments but 2 are required
    0
 }
@@ -0,0 +1,23 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[link(name = "foo", kind = "dynlib")] //~ ERROR unknown kind: `dynlib`
+extern {}
+
+#[link(kind = "dylib")] //~ ERROR specified without `name = "foo"`
+extern {}
+
+#[link(name = "bar", knid = "static")] //~ ERROR unknown argument for `#[link]`
+extern {}
+
+#[link(name = "baz", kind = "dylib")] // OK
+extern {}
+
+fn main() {}
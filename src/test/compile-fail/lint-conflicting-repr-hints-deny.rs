@@ -0,0 +1,25 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(conflicting_repr_hints)]
+#![allow(dead_code)]
+
+#[repr(u8, u16)] //~ ERROR conflicting representation hints
+enum Denied {
+    A,
+}
+
+#[allow(conflicting_repr_hints)]
+#[repr(C, u8)]
+enum Allowed {
+    A,
+}
+
+fn main() {}
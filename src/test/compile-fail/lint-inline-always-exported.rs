@@ -0,0 +1,26 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(rustc_attrs)]
+
+#[inline(always)] //~ WARN `#[inline(always)]` function is exported with `#[no_mangle]`
+#[no_mangle]
+pub fn exported() {}
+
+#[inline(always)] //~ WARN `#[inline(always)]` function is exported with `#[export_name]`
+#[export_name = "renamed"]
+pub fn renamed() {}
+
+#[inline] // OK: only a hint
+#[no_mangle]
+pub fn hinted() {}
+
+#[rustc_error]
+fn main() {} //~ ERROR compilation successful
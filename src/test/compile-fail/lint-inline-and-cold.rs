@@ -0,0 +1,28 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(inline_and_cold)]
+
+#[inline] //~ ERROR function is marked both `#[inline]` and `#[cold]`
+#[cold]
+pub fn plain() {}
+
+#[cold]
+#[inline(always)] //~ ERROR function is marked both `#[inline]` and `#[cold]`
+pub fn always() {}
+
+#[inline(never)] // OK
+#[cold]
+pub fn never() {}
+
+#[cold] // OK
+pub fn cold() {}
+
+fn main() {}
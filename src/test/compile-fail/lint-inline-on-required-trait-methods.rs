@@ -0,0 +1,26 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(inline_on_required_trait_methods)]
+
+trait Foo {
+    #[inline] //~ ERROR `#[inline]` has no effect on a trait method without a body
+    fn foo(&self);
+
+    #[inline] // OK: provided methods have a body to inline
+    fn bar(&self) {}
+}
+
+impl Foo for () {
+    #[inline] // OK
+    fn foo(&self) {}
+}
+
+fn main() {}
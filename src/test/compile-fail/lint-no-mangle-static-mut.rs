@@ -0,0 +1,28 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(rustc_attrs)]
+#![allow(dead_code, private_no_mangle_statics)]
+
+use std::sync::atomic::AtomicUsize;
+
+#[no_mangle]
+static mut COUNTER: i32 = 0; //~ WARN `#[no_mangle]` static is mutable
+
+#[no_mangle]
+pub static mut SHARED: [u8; 4] = [0; 4]; //~ WARN `#[no_mangle]` static is mutable
+
+#[no_mangle]
+pub static ATOMIC_COUNTER: AtomicUsize = AtomicUsize::new(0); // OK: interior mutability
+
+static mut UNEXPORTED: i32 = 0; // OK: not exported
+
+#[rustc_error]
+fn main() {} //~ ERROR compilation successful
@@ -0,0 +1,38 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(non_exhaustive)]
+#![deny(non_exhaustive_stable_repr)]
+
+#[non_exhaustive] //~ ERROR `#[non_exhaustive]` type has a stable representation
+#[repr(C)]
+pub struct S {
+    pub x: u32,
+}
+
+#[non_exhaustive] //~ ERROR `#[non_exhaustive]` type has a stable representation
+#[repr(u8)]
+pub enum E {
+    A,
+    B,
+}
+
+#[non_exhaustive] // OK, no stable representation
+#[repr(align(8))]
+pub struct Aligned {
+    pub x: u32,
+}
+
+#[repr(C)] // OK, exhaustive
+pub struct Exhaustive {
+    pub x: u32,
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(non_exhaustive)]
+#![deny(non_exhaustive_unit_structs)]
+
+#[non_exhaustive]
+//~^ ERROR unit struct has no fields to mark as non-exhaustive
+pub struct Unit;
+
+#[non_exhaustive]
+pub struct Tuple(pub u32);
+
+#[non_exhaustive]
+pub struct Named {
+    pub field: u32,
+}
+
+#[non_exhaustive]
+pub struct Empty {}
+
+fn main() {}
@@ -0,0 +1,43 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(packed_derive_references)]
+#![allow(safe_packed_borrows)]
+
+#[repr(packed)]
+#[derive(Debug)] //~ ERROR `#[derive(Debug)]` takes references to the fields of a packed struct
+struct S {
+    x: u32,
+}
+
+// Derives copy the fields of a `Copy` struct out before using them.
+#[repr(packed)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct T {
+    x: u32,
+}
+
+#[repr(packed)]
+#[derive(Default)] // OK: does not look at the fields
+struct U {
+    x: u32,
+}
+
+// Reported at the derive it came from, once for each trait.
+#[repr(packed)]
+#[derive(
+    Hash, //~ ERROR `#[derive(Hash)]` takes references to the fields of a packed struct
+    PartialEq, //~ ERROR `#[derive(PartialEq)]` takes references to the fields of a packed struct
+)]
+struct V {
+    x: String,
+}
+
+fn main() {}
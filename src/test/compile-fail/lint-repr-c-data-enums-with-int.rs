@@ -0,0 +1,35 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(repr_c_data_enums_with_int)]
+
+#[repr(C, u8)] //~ ERROR enum with fields has both `C` and `u8` representation hints
+enum E {
+    A(u32),
+    B,
+}
+
+#[repr(C)] //~ ERROR enum with fields has both `C` and `i32` representation hints
+#[repr(i32)]
+enum F {
+    A { x: u8 },
+}
+
+#[repr(u8)] // OK, no `C`
+enum G {
+    A(u32),
+}
+
+#[repr(C)] // OK, no integer hint
+enum H {
+    A(u32),
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(used)]
+#![deny(unreferenced_used_statics)]
+
+#[used] //~ ERROR `#[used]` static is never referenced
+static UNREFERENCED: u32 = 0;
+
+#[used] // OK: referenced below
+static REFERENCED: u32 = 1;
+
+#[used] // OK: exported
+#[no_mangle]
+#[link_section = ".data.exported"]
+pub static EXPORTED: u32 = 2;
+
+#[used] // OK: placed in a section
+#[link_section = ".init_array"]
+static PLACED: extern fn() = init;
+
+extern fn init() {}
+
+fn main() {
+    let _ = REFERENCED;
+}
@@ -0,0 +1,26 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(used)]
+#![deny(used_without_link_section)]
+
+#[used] //~ ERROR `#[used]` static is exported with `#[no_mangle]` but not placed in a section
+#[no_mangle]
+pub static FOO: u32 = 0;
+
+#[used]
+#[no_mangle]
+#[link_section = ".init_array"]
+pub static BAR: u32 = 0; // OK, placed in a section
+
+#[used]
+static BAZ: u32 = 0; // OK, not exported
+
+fn main() {}
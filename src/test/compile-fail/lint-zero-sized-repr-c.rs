@@ -0,0 +1,31 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(zero_sized_repr_c)]
+
+#[repr(C)] //~ ERROR `#[repr(C)]` struct has no fields
+struct Unit;
+
+#[repr(C)] //~ ERROR `#[repr(C)]` struct has no fields
+struct Braces {}
+
+#[repr(C)] //~ ERROR `#[repr(C)]` struct has no fields
+struct Tuple();
+
+#[repr(C)]
+struct OneField(u8); // OK
+
+#[repr(C)]
+union OneFieldUnion { x: u8 } // OK
+
+#[repr(C)]
+enum E { A } // OK, not a struct or union
+
+fn main() {}
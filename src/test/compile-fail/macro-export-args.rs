@@ -0,0 +1,25 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that `local_inner_macros` is the only argument `#[macro_export]` takes.
+
+#[macro_export(local_inner_macros)]
+macro_rules! ok { () => {} }
+
+#[macro_export(local_inner_macros, foo)] //~ ERROR unknown argument for `#[macro_export]`
+macro_rules! unknown { () => {} }
+
+#[macro_export(local_inner_macros = "yes")] //~ ERROR unknown argument for `#[macro_export]`
+macro_rules! name_value { () => {} }
+
+#[macro_export("local_inner_macros")] //~ ERROR unknown argument for `#[macro_export]`
+macro_rules! literal { () => {} }
+
+fn main() {}
@@ -0,0 +1,34 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#![feature(marker_trait_attr)]
+#![allow(dead_code)]
+
+#[marker] //~ ERROR attribute should be applied to a trait
+struct S;
+
+#[marker] //~ ERROR attribute should be applied to a trait
+impl S {}
+
+#[marker(always)] //~ ERROR attribute must be of the form `#[marker]`
+trait Args {}
+
+#[marker]
+trait Items {
+    const N: usize; //~ ERROR marker traits cannot have associated items
+    type T; //~ ERROR marker traits cannot have associated items
+    fn f(&self) {} //~ ERROR marker traits cannot have associated items
+}
+
+#[marker]
+trait Marker {}
+
+fn main() {}
@@ -0,0 +1,31 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[must_use] // OK
+trait Foo {
+    #[must_use] //~ WARN attribute should be applied to a function, struct, enum, union or trait
+    type Output;
+
+    #[must_use = "the result should be checked"] // OK
+    fn foo(&self) -> Self::Output;
+}
+
+impl Foo for () {
+    #[must_use] //~ WARN attribute should be applied to a function, struct, enum, union or trait
+    type Output = u8;
+
+    #[must_use] // OK
+    fn foo(&self) -> u8 { 0 }
+}
+
+#[must_use(reason)] //~ ERROR attribute must be of the form
+fn bar() {}
+
+fn main() {}
@@ -0,0 +1,33 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(naked_functions, stmt_expr_attributes)]
+#![allow(dead_code)]
+
+#[naked] //~ ERROR attribute should be applied to a function
+struct S;
+
+#[naked]
+fn rust_abi() {} //~ ERROR naked function must have an explicit ABI
+
+#[naked] // OK
+extern "C" fn c_abi() {}
+
+impl S {
+    #[naked]
+    fn method() {} //~ ERROR naked function must have an explicit ABI
+
+    #[naked] // OK
+    extern "C" fn c_method() {}
+}
+
+fn main() {
+    let _f = #[naked] || {}; //~ ERROR attribute should be applied to a function
+}
@@ -0,0 +1,39 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(no_mangle_generic_items)]
+
+pub struct Foo<T>(T);
+
+impl<T> Foo<T> {
+    #[no_mangle]
+    pub fn foo() {}
+    //~^ ERROR functions generic over types must be mangled
+
+    #[no_mangle]
+    pub fn bar(&self) {}
+    //~^ ERROR functions generic over types must be mangled
+}
+
+pub struct Bar;
+
+impl Bar {
+    #[no_mangle]
+    pub fn baz<U>() {}
+    //~^ ERROR functions generic over types must be mangled
+
+    #[no_mangle]
+    pub fn qux<'a>(_: &'a u8) {} // OK, only generic over lifetimes
+
+    #[no_mangle]
+    pub fn quux() {} // OK
+}
+
+fn main() {}
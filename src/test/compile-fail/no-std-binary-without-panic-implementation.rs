@@ -0,0 +1,19 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A `#![no_std]` binary without a panic implementation is reported at its
+// `#![no_std]`.
+
+#![feature(lang_items)]
+#![no_main]
+#![no_std] //~ ERROR `#[panic_implementation]` function required, but not found
+
+#[lang = "eh_personality"]
+fn eh() {}
@@ -9,16 +9,19 @@
 // except according to those terms.
 #![allow(dead_code)]
 
-#[repr(align(16.0))] //~ ERROR: invalid `repr(align)` attribute: not an unsuffixed integer
+#[repr(align(16.0))] //~ ERROR: `repr(align)` argument must be an unsuffixed integer
 struct A(i32);
 
-#[repr(align(15))] //~ ERROR: invalid `repr(align)` attribute: not a power of two
+#[repr(align(15))] //~ ERROR: `repr(align)` argument must be a power of two
 struct B(i32);
 
-#[repr(align(4294967296))] //~ ERROR: invalid `repr(align)` attribute: larger than 2^29
+#[repr(align(4294967296))] //~ ERROR: alignment of 4294967296 bytes is not supported
 struct C(i32);
 
 #[repr(align(536870912))] // ok: this is the largest accepted alignment
 struct D(i32);
 
+#[repr(align(16u32))] //~ ERROR: `repr(align)` argument must be an unsuffixed integer
+struct E(i32);
+
 fn main() {}
@@ -0,0 +1,27 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(overflowing_literals)]
+
+#[repr(C, u8)]
+enum E {
+    A = 1,
+    B = 255,
+    C = 256, //~ ERROR discriminant value `256` does not fit in `u8`
+}
+
+#[repr(C, i8)]
+enum F {
+    A = -129, //~ ERROR discriminant value `-129` does not fit in `i8`
+    B = -128,
+    C = 0,
+}
+
+fn main() {}
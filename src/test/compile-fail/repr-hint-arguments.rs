@@ -0,0 +1,32 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[repr(C(1))] //~ ERROR `C` representation hint takes no arguments
+struct A(u8);
+
+#[repr(transparent = "x")] //~ ERROR `transparent` representation hint takes no value
+struct B(u8);
+
+#[repr(u8(2))] //~ ERROR `u8` representation hint takes no arguments
+enum C { X }
+
+#[repr(align)] //~ ERROR `align` representation hint needs an argument
+struct D(u8);
+
+#[repr(packed(1, 2))] //~ ERROR `packed` representation hint takes exactly one integer argument
+struct E(u8);
+
+#[repr(packed = 2)] //~ ERROR incorrect `repr(packed)` hint format
+struct F(u8);
+
+#[repr(C, align(8))] // ok
+struct G(u8);
+
+fn main() {}
@@ -0,0 +1,51 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Every representation hint is checked against the targets it applies to and the
+// arguments it takes.
+
+#![feature(repr_simd, repr_packed, repr128, repr_align_enum)]
+#![allow(dead_code)]
+
+#[repr(C)] // ok
+enum C1 { A }
+#[repr(C = "x")] //~ ERROR `C` representation hint takes no value
+struct C2(u8);
+
+#[repr(packed)] //~ ERROR `repr(packed)` attribute should be applied to struct or union
+enum Packed1 { A }
+#[repr(packed(2))] // ok
+union Packed2 { a: u8 }
+#[repr(packed = 2)] //~ ERROR incorrect `repr(packed)` hint format
+struct Packed3(u8);
+
+#[repr(simd)] //~ ERROR `repr(simd)` attribute should be applied to struct
+enum Simd1 { A }
+#[repr(simd(4))] //~ ERROR `simd` representation hint takes no arguments
+struct Simd2(f32, f32, f32, f32);
+
+#[repr(align(8))] //~ ERROR `repr(align(8))` attribute should be applied to struct, enum or union
+fn align1() {}
+#[repr(align(1, 2))] //~ ERROR `align` representation hint takes exactly one integer argument
+struct Align2(u8);
+
+#[repr(transparent)] //~ ERROR `repr(transparent)` attribute should be applied to struct, enum
+fn transparent1() {}
+#[repr(transparent(1))] //~ ERROR `transparent` representation hint takes no arguments
+struct Transparent2(u8);
+
+#[repr(i8)] //~ ERROR `repr(i8)` attribute should be applied to enum
+struct Int1(u8);
+#[repr(u128)] //~ ERROR `repr(u128)` attribute should be applied to enum
+union Int2 { a: u8 }
+#[repr(isize = "x")] //~ ERROR `isize` representation hint takes no value
+enum Int3 { A }
+
+fn main() {}
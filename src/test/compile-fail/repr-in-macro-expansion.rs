@@ -0,0 +1,20 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+macro_rules! c_fn {
+    ($name:ident) => {
+        #[repr(C)] //~ ERROR attribute should be applied to struct, enum or union
+        fn $name() {}
+    }
+}
+
+c_fn!(foo); //~ NOTE the attribute was generated by this invocation of `c_fn!`
+
+fn main() {}
@@ -0,0 +1,32 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+trait Tr {
+    #[repr(C)] //~ ERROR attribute should not be applied to an associated type
+    type Ty;
+
+    #[repr(C)] //~ ERROR attribute should not be applied to an associated const
+    const C: u32;
+}
+
+struct S;
+
+impl Tr for S {
+    #[repr(C)] //~ ERROR attribute should not be applied to an associated type
+    type Ty = u8;
+
+    #[repr(C)] //~ ERROR attribute should not be applied to an associated const
+    const C: u32 = 0;
+}
+
+#[repr(C)] //~ ERROR attribute should be applied to struct, enum or union
+type Alias = u8;
+
+fn main() {}
@@ -0,0 +1,31 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Fields have the representation of their type, and variants that of their
+// enum, `#[repr]` can't change it.
+
+union U {
+    #[repr(C)] //~ ERROR attribute should not be applied to a union field
+    a: u32,
+    b: f32,
+}
+
+struct S {
+    #[repr(packed)] //~ ERROR attribute should not be applied to a field
+    a: u32,
+}
+
+enum E {
+    A(#[repr(align(8))] u8), //~ ERROR attribute should not be applied to a field
+    #[repr(u8)] //~ ERROR attribute should not be applied to an enum variant
+    B,
+}
+
+fn main() {}
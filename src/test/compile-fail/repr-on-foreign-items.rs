@@ -0,0 +1,20 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[repr(C)] //~ ERROR attribute should not be applied to an `extern` block
+extern "C" {
+    #[repr(C)] //~ ERROR attribute should not be applied to a foreign function
+    fn foo();
+
+    #[repr(align(8))] //~ ERROR attribute should not be applied to a foreign static
+    static BAR: u32;
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+#![feature(repr_packed, untagged_unions)]
+
+#[repr(packed(3))] //~ ERROR: `repr(packed)` argument must be a power of two
+struct A(i32);
+
+#[repr(packed(2u8))] //~ ERROR: `repr(packed)` argument must be an unsuffixed integer
+struct B(i32);
+
+#[repr(packed(1073741824))] //~ ERROR: alignment of 1073741824 bytes is not supported
+struct C(i32);
+
+#[repr(packed(2))] //~ ERROR: attribute should be applied to struct or union
+enum D { D }
+
+#[repr(packed(3))] //~ ERROR: `repr(packed)` argument must be a power of two
+union E {
+    i: i32,
+}
+
+#[repr(packed(2))] // ok
+struct F(i32);
+
+#[repr(packed(2))] // ok
+union G {
+    i: i32,
+}
+
+fn main() {}
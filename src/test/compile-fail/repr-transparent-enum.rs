@@ -0,0 +1,36 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(transparent_enums)]
+
+use std::marker::PhantomData;
+
+#[repr(transparent)] // OK
+enum Single {
+    Value(u32, PhantomData<u8>),
+}
+
+#[repr(transparent)]
+enum TwoVariants { //~ ERROR transparent enum needs exactly one variant, but has 2
+    A(u32),
+    B(u32),
+}
+
+#[repr(transparent)]
+enum TwoFields { //~ ERROR transparent enum needs exactly one non-zero-sized field, but has 2
+    Value(u32, f32),
+}
+
+#[repr(transparent, u8)] //~ ERROR transparent enum cannot have other repr hints
+enum WithInt {
+    Value(u8),
+}
+
+fn main() {}
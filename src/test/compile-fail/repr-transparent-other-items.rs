@@ -11,21 +11,21 @@
 // See also repr-transparent.rs
 
 #[repr(transparent)] //~ ERROR unsupported representation for zero-variant enum
-enum Void {}         //~| ERROR should be applied to struct
+enum Void {}         //~| ERROR transparent enums are unstable
 
-#[repr(transparent)] //~ ERROR should be applied to struct
+#[repr(transparent)] //~ ERROR transparent enums are unstable
 enum FieldlessEnum {
     Foo,
     Bar,
 }
 
-#[repr(transparent)] //~ ERROR should be applied to struct
+#[repr(transparent)] //~ ERROR transparent enums are unstable
 enum Enum {
     Foo(String),
     Bar(u32),
 }
 
-#[repr(transparent)] //~ ERROR should be applied to struct
+#[repr(transparent)] //~ ERROR transparent unions are unstable
 union Foo {
     u: u32,
     s: i32
@@ -0,0 +1,32 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(transparent_unions)]
+
+use std::marker::PhantomData;
+
+#[repr(transparent)] // OK
+union Single {
+    x: u32,
+    marker: PhantomData<u8>,
+}
+
+#[repr(transparent)]
+union Two { //~ ERROR transparent union needs exactly one non-zero-sized field, but has 2
+    x: u32,
+    y: f32,
+}
+
+#[repr(transparent)] //~ ERROR transparent enums are unstable
+enum NotAllowed {
+    X(u32),
+}
+
+fn main() {}
@@ -11,18 +11,26 @@
 #![feature(non_exhaustive)]
 
 #[non_exhaustive(anything)]
-//~^ ERROR attribute should be empty [E0702]
+//~^ ERROR attribute must be of the form `#[non_exhaustive]` [E0702]
 struct Foo;
 
 #[non_exhaustive]
-//~^ ERROR attribute can only be applied to a struct or enum [E0701]
+//~^ ERROR attribute should be applied to a struct, enum or enum variant [E0701]
 trait Bar { }
 
 #[non_exhaustive]
-//~^ ERROR attribute can only be applied to a struct or enum [E0701]
+//~^ ERROR attribute should be applied to a struct, enum or enum variant [E0701]
 union Baz {
     f1: u16,
     f2: u16
 }
 
+enum Qux {
+    #[non_exhaustive] // OK
+    Unit,
+    #[non_exhaustive(anything)]
+    //~^ ERROR attribute must be of the form `#[non_exhaustive]` [E0702]
+    Tuple(u32),
+}
+
 fn main() { }
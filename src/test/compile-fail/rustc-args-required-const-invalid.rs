@@ -0,0 +1,35 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(attr_literals, rustc_attrs)]
+
+#[rustc_args_required_const(0, 1)] // OK
+fn foo(_a: i32, _b: i32) {}
+
+#[rustc_args_required_const(2)] //~ ERROR index exceeds number of arguments
+fn bar(_a: i32, _b: i32) {}
+
+#[rustc_args_required_const(a, "b")] //~ ERROR arguments should be non-negative integers
+fn baz(_a: i32) {}
+
+#[rustc_args_required_const] //~ ERROR must be of the form
+fn qux(_a: i32) {}
+
+#[rustc_args_required_const(0)] //~ ERROR attribute should be applied to a function
+struct S;
+
+struct T;
+
+impl T {
+    #[rustc_args_required_const(2)] //~ ERROR index exceeds number of arguments
+    fn method(&self, _a: i32) {}
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[export_name = "foo\0bar"]
+//~^ ERROR `export_name` may not contain null characters [E0648]
+pub fn foo() {}
+
+#[export_name = "bar\0"]
+//~^ ERROR `export_name` may not contain null characters [E0648]
+pub static BAR: u32 = 0;
+
+#[export_name = "baz"] // OK
+pub fn baz() {}
+
+// A misplaced `#[export_name]` has no effect, but its name is still checked.
+#[export_name = "gar\0ply"]
+//~^ ERROR `export_name` may not contain null characters [E0648]
+//~| WARN attribute should be applied to a function or static
+struct Garply;
+
+#[export_name]
+//~^ ERROR attribute must be of the form
+//~| WARN attribute should be applied to a function or static
+struct Waldo;
+
+extern {
+    #[link_name = "qu\0x"]
+    //~^ ERROR `link_name` may not contain null characters [E0648]
+    fn qux();
+
+    #[link_name = "quux"] // OK
+    fn quux();
+}
+
+#[link_section = ".text\0"]
+//~^ ERROR `link_section` may not contain null characters [E0648]
+pub fn corge() {}
+
+#[link_section = ".data"] // OK
+pub static GRAULT: u32 = 0;
+
+fn main() {}
@@ -0,0 +1,34 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(thread_local)]
+#![allow(dead_code)]
+
+#[thread_local] //~ ERROR attribute should be applied to a static
+fn foo() {}
+
+#[thread_local] //~ ERROR attribute should be applied to a static
+struct Bar;
+
+#[thread_local] //~ ERROR attribute should be applied to a static
+const BAZ: u32 = 0;
+
+#[thread_local] // OK
+static QUX: u32 = 0;
+
+#[thread_local] // OK
+static mut QUUX: u32 = 0;
+
+extern {
+    #[thread_local] // OK
+    static CORGE: u32;
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(naked_functions, stmt_expr_attributes, track_caller)]
+#![allow(dead_code)]
+
+#[track_caller] //~ ERROR attribute should be applied to a function
+struct S;
+
+#[track_caller]
+extern "C" fn c_abi() {} //~ ERROR `#[track_caller]` requires the Rust ABI
+
+#[track_caller] //~ ERROR conflicting codegen attributes on this function
+#[naked]
+fn naked() {} //~ ERROR naked function must have an explicit ABI
+
+trait T {
+    #[track_caller]
+    fn required(&self); //~ ERROR `#[track_caller]` is not supported on trait methods
+
+    #[track_caller]
+    fn provided(&self) {} //~ ERROR `#[track_caller]` is not supported on trait methods
+}
+
+impl T for S {
+    #[track_caller]
+    fn required(&self) {} //~ ERROR `#[track_caller]` is not supported on trait methods
+}
+
+impl S {
+    #[track_caller] // OK
+    fn inherent(&self) {}
+}
+
+#[track_caller] // OK
+fn free() {}
+
+fn main() {
+    let _f = #[track_caller] || {}; //~ ERROR attribute should be applied to a function
+}
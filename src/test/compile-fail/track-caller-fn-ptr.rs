@@ -0,0 +1,20 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(track_caller)]
+
+#[track_caller]
+fn tracked() {}
+
+fn main() {
+    let _: fn() = tracked; //~ ERROR cannot be coerced to a function pointer
+    let _ = tracked as fn(); //~ ERROR cannot be coerced to a function pointer
+    let _: fn() = || tracked(); // OK
+}
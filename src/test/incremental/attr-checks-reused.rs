@@ -0,0 +1,40 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test that changing an item in one module does not check the attributes of
+// the other modules again, and that the diagnostics found for them before are
+// still reported.
+
+// revisions: cfail1 cfail2
+// compile-flags: -Z query-dep-graph
+// compile-pass
+
+#![feature(rustc_attrs)]
+#![allow(dead_code)]
+#![crate_type = "rlib"]
+
+#[rustc_clean(label="CheckModAttrs", cfg="cfail2")]
+pub mod untouched {
+    #[inline]
+    #[inline] //~ WARNING `#[inline]` is specified more than once
+    pub fn untouched() {}
+
+    pub struct Untouched;
+}
+
+#[cfg(cfail1)]
+pub fn touched() -> u32 {
+    1
+}
+
+#[cfg(not(cfail1))]
+pub fn touched() -> u32 {
+    2
+}
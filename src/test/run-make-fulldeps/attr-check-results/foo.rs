@@ -0,0 +1,130 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(rustc_private)]
+
+extern crate rustc;
+extern crate rustc_driver;
+extern crate rustc_lint;
+extern crate rustc_metadata;
+extern crate rustc_errors;
+extern crate rustc_codegen_utils;
+extern crate syntax;
+
+use rustc::hir::check_attr::{AttrCheckOutcome, Target};
+use rustc::hir::def_id::LOCAL_CRATE;
+use rustc::session::{build_session, Session};
+use rustc::session::config::{basic_options, Input, Options};
+use rustc_driver::Compilation;
+use rustc_driver::driver::{self, compile_input, CompileController};
+use rustc_metadata::cstore::CStore;
+use rustc_errors::registry::Registry;
+use syntax::codemap::FileName;
+use rustc_codegen_utils::codegen_backend::CodegenBackend;
+
+use std::cell::Cell;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+fn main() {
+    let src = r#"
+    #[inline]
+    struct S;
+
+    #[inline]
+    fn f() {}
+
+    #[cold]
+    struct C;
+
+    #[allow(misplaced_inert_attributes)]
+    #[cold]
+    struct D;
+
+    #[deny(misplaced_inert_attributes)]
+    #[cold]
+    struct E;
+
+    fn main() {}
+    "#;
+
+    let args: Vec<String> = std::env::args().collect();
+
+    if args.len() < 4 {
+        panic!("expected rustc path");
+    }
+
+    let tmpdir = PathBuf::from(&args[1]);
+
+    let mut sysroot = PathBuf::from(&args[3]);
+    sysroot.pop();
+    sysroot.pop();
+
+    compile(src.to_string(), tmpdir.join("out"), sysroot);
+}
+
+fn basic_sess(opts: Options) -> (Session, Rc<CStore>, Box<CodegenBackend>) {
+    let descriptions = Registry::new(&rustc::DIAGNOSTICS);
+    let sess = build_session(opts, None, descriptions);
+    let codegen_backend = rustc_driver::get_codegen_backend(&sess);
+    let cstore = Rc::new(CStore::new(codegen_backend.metadata_loader()));
+    rustc_lint::register_builtins(&mut sess.lint_store.borrow_mut(), Some(&sess));
+    (sess, cstore, codegen_backend)
+}
+
+fn compile(code: String, output: PathBuf, sysroot: PathBuf) {
+    syntax::with_globals(|| {
+        let mut opts = basic_options();
+        opts.maybe_sysroot = Some(sysroot);
+        driver::spawn_thread_pool(opts, |opts| {
+            let (sess, cstore, codegen_backend) = basic_sess(opts);
+            let checked = Cell::new(false);
+            let mut control = CompileController::basic();
+            control.after_analysis.stop = Compilation::Stop;
+            control.after_analysis.callback = Box::new(|state| {
+                let tcx = state.tcx.unwrap();
+                let results = tcx.attr_check_results(LOCAL_CRATE);
+
+                // The `#[inline]` on `f` is the only attribute that is not misplaced.
+                assert_eq!(results.len(), 4);
+                assert_eq!(results[0].attr_name, "inline");
+                assert_eq!(tcx.item_path_str(results[0].def_id), "S");
+                assert_eq!(results[0].target, Target::Struct);
+                assert_eq!(results[0].outcome, AttrCheckOutcome::Error);
+
+                // A misplaced `#[cold]` is reported at the level of
+                // `misplaced_inert_attributes`, which warns by default.
+                let outcomes: Vec<_> = results[1..].iter().map(|result| {
+                    assert_eq!(result.attr_name, "cold");
+                    (tcx.item_path_str(result.def_id), result.outcome)
+                }).collect();
+                assert_eq!(outcomes, vec![
+                    ("C".to_string(), AttrCheckOutcome::Warning),
+                    ("D".to_string(), AttrCheckOutcome::Allowed),
+                    ("E".to_string(), AttrCheckOutcome::Error),
+                ]);
+                checked.set(true);
+            });
+            let input = Input::Str { name: FileName::Anon, input: code };
+            let _ = compile_input(
+                codegen_backend,
+                &sess,
+                &cstore,
+                &None,
+                &input,
+                &None,
+                &Some(output),
+                None,
+                &control
+            );
+            assert!(checked.get());
+        });
+    });
+}
@@ -0,0 +1,30 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+// Impls of a `#[marker]` trait are allowed to overlap.
+
+#![feature(marker_trait_attr)]
+
+use std::fmt::{Debug, Display};
+
+#[marker]
+trait Printable {}
+
+impl<T: Display> Printable for T {}
+impl<T: Debug> Printable for T {}
+
+fn is_printable<T: Printable>(_: T) -> bool { true }
+
+fn main() {
+    assert!(is_printable(1));
+    assert!(is_printable("str"));
+    assert!(is_printable(vec![1, 2]));
+}
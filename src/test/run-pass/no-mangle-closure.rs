@@ -0,0 +1,20 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// `#[no_mangle]` on a closure only warns, and the closures keep their mangled
+// names, so that the two of them don't clash.
+
+#![feature(stmt_expr_attributes)]
+
+fn main() {
+    let f = #[no_mangle] || 1;
+    let g = #[no_mangle] || 2;
+    assert_eq!(f() + g(), 3);
+}
@@ -0,0 +1,55 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(repr_align_enum)]
+#![allow(dead_code)]
+
+use std::mem;
+
+// Raising the alignment of an enum raises the alignment of every variant, and
+// pads the enum out to a multiple of it.
+
+#[repr(align(8))]
+enum Fieldless {
+    A,
+    B,
+}
+
+#[repr(u8, align(16))]
+enum Tagged {
+    A(u8),
+    B(u16),
+}
+
+#[repr(C, align(32))]
+enum CTagged {
+    A(u32),
+    B,
+}
+
+#[repr(align(4))]
+enum Niche {
+    Some(&'static u8),
+    None,
+}
+
+fn main() {
+    assert_eq!(mem::align_of::<Fieldless>(), 8);
+    assert_eq!(mem::size_of::<Fieldless>(), 8);
+
+    assert_eq!(mem::align_of::<Tagged>(), 16);
+    assert_eq!(mem::size_of::<Tagged>(), 16);
+
+    assert_eq!(mem::align_of::<CTagged>(), 32);
+    assert_eq!(mem::size_of::<CTagged>(), 32);
+
+    assert_eq!(mem::align_of::<Niche>(), mem::align_of::<&u8>().max(4));
+    assert_eq!(mem::size_of::<Niche>(), mem::size_of::<&u8>());
+}
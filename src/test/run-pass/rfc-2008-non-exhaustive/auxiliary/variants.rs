@@ -16,3 +16,11 @@ pub enum NonExhaustiveVariants {
     #[non_exhaustive] Tuple(u32),
     #[non_exhaustive] Struct { field: u32 }
 }
+
+impl NonExhaustiveVariants {
+    pub fn unit() -> NonExhaustiveVariants { NonExhaustiveVariants::Unit }
+    pub fn tuple(x: u32) -> NonExhaustiveVariants { NonExhaustiveVariants::Tuple(x) }
+    pub fn struct_(field: u32) -> NonExhaustiveVariants {
+        NonExhaustiveVariants::Struct { field }
+    }
+}
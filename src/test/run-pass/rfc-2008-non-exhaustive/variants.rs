@@ -13,19 +13,19 @@ extern crate variants;
 
 use variants::NonExhaustiveVariants;
 
-/*
- * The initial implementation of #[non_exhaustive] (RFC 2008) does not include support for
- * variants. See issue #44109 and PR 45394.
- */
-// ignore-test
+// The constructors of variants marked `#[non_exhaustive]` are private to
+// their crate, so they have to be created through it, and matched with
+// struct patterns that end in `..`.
+fn describe(variant: NonExhaustiveVariants) -> (&'static str, Option<u32>) {
+    match variant {
+        NonExhaustiveVariants::Unit { .. } => ("unit", None),
+        NonExhaustiveVariants::Struct { field, .. } => ("struct", Some(field)),
+        NonExhaustiveVariants::Tuple { 0: fe_tpl, .. } => ("tuple", Some(fe_tpl)),
+    }
+}
 
 fn main() {
-    let variant_tuple = NonExhaustiveVariants::Tuple { 0: 340 };
-    let variant_struct = NonExhaustiveVariants::Struct { field: 340 };
-
-    match variant_struct {
-        NonExhaustiveVariants::Unit => "",
-        NonExhaustiveVariants::Struct { field, .. } => "",
-        NonExhaustiveVariants::Tuple(fe_tpl, ..) => ""
-    };
+    assert_eq!(describe(NonExhaustiveVariants::unit()), ("unit", None));
+    assert_eq!(describe(NonExhaustiveVariants::tuple(640)), ("tuple", Some(640)));
+    assert_eq!(describe(NonExhaustiveVariants::struct_(340)), ("struct", Some(340)));
 }
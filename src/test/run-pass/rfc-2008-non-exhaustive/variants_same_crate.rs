@@ -10,12 +10,6 @@
 
 #![feature(non_exhaustive)]
 
-/*
- * The initial implementation of #[non_exhaustive] (RFC 2008) does not include support for
- * variants. See issue #44109 and PR 45394.
- */
-// ignore-test
-
 pub enum NonExhaustiveVariants {
     #[non_exhaustive] Unit,
     #[non_exhaustive] Tuple(u32),
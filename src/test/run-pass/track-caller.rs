@@ -0,0 +1,46 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(const_fn, track_caller)]
+
+use std::panic::Location;
+
+#[track_caller]
+fn tracked() -> &'static Location<'static> {
+    Location::caller()
+}
+
+#[track_caller]
+fn nested() -> &'static Location<'static> {
+    tracked()
+}
+
+fn untracked() -> &'static Location<'static> {
+    tracked()
+}
+
+#[track_caller]
+const fn const_tracked() -> &'static Location<'static> {
+    Location::caller()
+}
+
+const LOCATION: &Location = const_tracked();
+
+fn main() {
+    assert_eq!(tracked().file(), file!());
+    assert_eq!(tracked().line(), line!());
+    assert_eq!(nested().line(), line!());
+    assert_eq!(untracked().line(), 26);
+    assert_eq!(LOCATION.line(), 34);
+    assert_eq!(const_tracked().line(), line!());
+
+    let from_closure = || tracked();
+    assert_eq!(from_closure().line(), line!() - 1);
+}
@@ -0,0 +1,30 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Attributes on the items of a trait are checked like those on other items.
+
+trait Tr {
+    #[repr(C)] //~ ERROR attribute should not be applied to an associated const
+    const C: u8;
+
+    #[inline] //~ ERROR attribute should be applied to function or closure
+    const D: u8 = 0;
+
+    #[inline] //~ ERROR attribute should be applied to function or closure
+    type T;
+
+    #[repr(C)] //~ ERROR `repr(C)` attribute should be applied to struct, enum or union
+    fn required(&self);
+
+    #[inline] // OK
+    fn provided(&self) {}
+}
+
+fn main() {}
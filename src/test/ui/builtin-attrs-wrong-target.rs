@@ -0,0 +1,20 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(used, non_exhaustive, stmt_expr_attributes)]
+
+#[non_exhaustive] //~ ERROR should be applied to a struct, enum or enum variant
+trait Tr {}
+
+fn main() {
+    #[used] //~ ERROR should be applied to a `static` variable
+    let x = 0;
+    let _ = #[used] x; //~ ERROR should be applied to a `static` variable
+}
@@ -0,0 +1,34 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-pass
+
+// The items generated by `#[derive]` carry attributes of their own, e.g.
+// `#[inline]` on the methods of the generated impls. None of them should be
+// reported by attribute checking.
+
+#![no_builtins]
+#![deny(warnings)]
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Unit;
+
+#[derive(Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Pair<T>(pub T, pub T);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Kind {
+    A,
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-pass
+
+#[export_name = "has space"]
+//~^ WARN `export_name` is not a valid symbol name on this target
+pub fn foo() {}
+
+#[export_name = "tab\tbed"]
+//~^ WARN `export_name` is not a valid symbol name on this target
+pub static BAR: u32 = 0;
+
+#[export_name = "baz$qux.1"] // OK
+pub fn baz() {}
+
+#[allow(invalid_symbol_names)]
+#[export_name = "allowed name"] // OK, the name is needed as it is
+pub fn allowed() {}
+
+fn main() {}
@@ -51,7 +51,7 @@
 #![forbid                      (x5200)] //~ WARN unknown lint: `x5200`
 #![deny                        (x5100)] //~ WARN unknown lint: `x5100`
 #![macro_use] // (allowed if no argument; see issue-43160-gating-of-macro_use.rs)
-#![macro_export               = "4800"] //~ WARN unused attribute
+// see issue-43106-gating-of-macro_export.rs
 #![plugin_registrar           = "4700"] //~ WARN unused attribute
 // skipping testing of cfg
 // skipping testing of cfg_attr
@@ -62,11 +62,11 @@
 #![repr                       = "3900"]
 //~^ WARN unused attribute
 //~| WARN `repr` attribute isn't configurable with a literal
-#![path                       = "3800"] //~ WARN unused attribute
+// see issue-43106-gating-of-path.rs
 #![abi                        = "3700"] //~ WARN unused attribute
 #![automatically_derived      = "3600"] //~ WARN unused attribute
-#![no_mangle                  = "3500"]
-#![no_link                    = "3400"] //~ WARN unused attribute
+#![no_mangle                  = "3500"] // see issue-43106-gating-of-no_mangle.rs for non crate-level
+// see issue-43106-gating-of-no_link.rs
 // see issue-43106-gating-of-derive.rs
 #![should_panic               = "3200"] //~ WARN unused attribute
 #![ignore                     = "3100"] //~ WARN unused attribute
@@ -77,16 +77,16 @@
 // (cannot easily test gating of crate-level #[no_std]; but non crate-level is below at "2600")
 #![proc_macro_derive          = "2500"] //~ WARN unused attribute
 #![doc                        = "2400"]
-#![cold                       = "2300"]
-#![export_name                = "2200"]
+// see issue-43106-gating-of-cold.rs
+#![export_name                = "2200"] // see issue-43106-gating-of-export_name.rs for non crate-level
 // see issue-43106-gating-of-inline.rs
 #![link                       = "2000"]
-#![link_name                  = "1900"]
-#![link_section               = "1800"]
+#![link_name                  = "1900"] // see issue-43106-gating-of-link_name.rs for non crate-level
+#![link_section               = "1800"] // see issue-43106-gating-of-link_section.rs for non crate-level
 #![no_builtins                = "1700"] // Yikes, dupe'd on BUILTIN_ATTRIBUTES list (see "0300")
 #![no_mangle                  = "1600"] // Yikes, dupe'd on BUILTIN_ATTRIBUTES list (see "3500")
 // see issue-43106-gating-of-rustc_deprecated.rs
-#![must_use                   = "1400"]
+#![must_use                   = "1400"] // see issue-43106-gating-of-must_use.rs for non crate-level
 // see issue-43106-gating-of-stable.rs
 // see issue-43106-gating-of-unstable.rs
 // see issue-43106-gating-of-deprecated.rs
@@ -204,25 +204,6 @@ mod macro_use {
     //~^ WARN unused attribute
 }
 
-#[macro_export = "4800"]
-//~^ WARN unused attribute
-mod macro_export {
-    mod inner { #![macro_export="4800"] }
-    //~^ WARN unused attribute
-
-    #[macro_export = "4800"] fn f() { }
-    //~^ WARN unused attribute
-
-    #[macro_export = "4800"] struct S;
-    //~^ WARN unused attribute
-
-    #[macro_export = "4800"] type T = S;
-    //~^ WARN unused attribute
-
-    #[macro_export = "4800"] impl S { }
-    //~^ WARN unused attribute
-}
-
 #[plugin_registrar = "4700"]
 //~^ WARN unused attribute
 mod plugin_registrar {
@@ -332,23 +313,6 @@ mod repr {
     //~| WARN `repr` attribute isn't configurable with a literal
 }
 
-#[path = "3800"]
-mod path {
-    mod inner { #![path="3800"] }
-
-    #[path = "3800"] fn f() { }
-    //~^ WARN unused attribute
-
-    #[path = "3800"]  struct S;
-    //~^ WARN unused attribute
-
-    #[path = "3800"] type T = S;
-    //~^ WARN unused attribute
-
-    #[path = "3800"] impl S { }
-    //~^ WARN unused attribute
-}
-
 #[abi = "3700"]
 //~^ WARN unused attribute
 mod abi {
@@ -387,39 +351,6 @@ mod automatically_derived {
     //~^ WARN unused attribute
 }
 
-#[no_mangle = "3500"]
-mod no_mangle {
-    mod inner { #![no_mangle="3500"] }
-
-    #[no_mangle = "3500"] fn f() { }
-    //~^ WARN function is marked #[no_mangle], but not exported
-
-    #[no_mangle = "3500"] struct S;
-
-    #[no_mangle = "3500"] type T = S;
-
-    #[no_mangle = "3500"] impl S { }
-}
-
-#[no_link = "3400"]
-//~^ WARN unused attribute
-mod no_link {
-    mod inner { #![no_link="3400"] }
-    //~^ WARN unused attribute
-
-    #[no_link = "3400"] fn f() { }
-    //~^ WARN unused attribute
-
-    #[no_link = "3400"] struct S;
-    //~^ WARN unused attribute
-
-    #[no_link = "3400"]type T = S;
-    //~^ WARN unused attribute
-
-    #[no_link = "3400"] impl S { }
-    //~^ WARN unused attribute
-}
-
 #[should_panic = "3200"]
 //~^ WARN unused attribute
 mod should_panic {
@@ -558,32 +489,6 @@ mod doc {
     #[doc = "2400"] impl S { }
 }
 
-#[cold = "2300"]
-mod cold {
-    mod inner { #![cold="2300"] }
-
-    #[cold = "2300"] fn f() { }
-
-    #[cold = "2300"] struct S;
-
-    #[cold = "2300"] type T = S;
-
-    #[cold = "2300"] impl S { }
-}
-
-#[export_name = "2200"]
-mod export_name {
-    mod inner { #![export_name="2200"] }
-
-    #[export_name = "2200"] fn f() { }
-
-    #[export_name = "2200"] struct S;
-
-    #[export_name = "2200"] type T = S;
-
-    #[export_name = "2200"] impl S { }
-}
-
 // Note that this test ends with a `#[rustc_error] fn main()`, so it
 // will never invoke the linker. These are here nonetheless to point
 // out that we allow them at non-crate-level (though I do not know
@@ -602,32 +507,6 @@ mod link {
     #[link = "2000"] impl S { }
 }
 
-#[link_name = "1900"]
-mod link_name {
-    mod inner { #![link_name="1900"] }
-
-    #[link_name = "1900"] fn f() { }
-
-    #[link_name = "1900"] struct S;
-
-    #[link_name = "1900"] type T = S;
-
-    #[link_name = "1900"] impl S { }
-}
-
-#[link_section = "1800"]
-mod link_section {
-    mod inner { #![link_section="1800"] }
-
-    #[link_section = "1800"] fn f() { }
-
-    #[link_section = "1800"] struct S;
-
-    #[link_section = "1800"] type T = S;
-
-    #[link_section = "1800"] impl S { }
-}
-
 struct StructForDeprecated;
 
 #[deprecated = "1500"]
@@ -643,19 +522,6 @@ mod deprecated {
     #[deprecated = "1500"] impl super::StructForDeprecated { }
 }
 
-#[must_use = "1400"]
-mod must_use {
-    mod inner { #![must_use="1400"] }
-
-    #[must_use = "1400"] fn f() { }
-
-    #[must_use = "1400"] struct S;
-
-    #[must_use = "1400"] type T = S;
-
-    #[must_use = "1400"] impl S { }
-}
-
 #[windows_subsystem = "1000"]
 mod windows_subsystem {
     mod inner { #![windows_subsystem="1000"] }
@@ -0,0 +1,39 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// This is testing whether `#[cold]` signals an error or warning
+// when put in "weird" places.
+//
+// (This file sits on its own because it actually signals an error,
+// which would mess up the treatment of other cases in
+// issue-43106-gating-of-builtin-attrs.rs)
+
+// Crate-level is rejected: it would not apply to any function.
+#![cold                       = "2300"] //~ ERROR cannot be applied to a crate
+
+#[cold = "2300"]
+//~^ WARN attribute should be applied to a function or closure
+mod cold {
+    mod inner { #![cold="2300"] }
+    //~^ WARN attribute should be applied to a function or closure
+
+    #[cold = "2300"] fn f() { } //~ ERROR attribute must be of the form
+
+    #[cold = "2300"] struct S;
+    //~^ WARN attribute should be applied to a function or closure
+
+    #[cold = "2300"] type T = S;
+    //~^ WARN attribute should be applied to a function or closure
+
+    #[cold = "2300"] impl S { }
+    //~^ WARN attribute should be applied to a function or closure
+}
+
+fn main() {}
@@ -15,8 +15,8 @@
 // which would mess up the treatment of other cases in
 // issue-43106-gating-of-builtin-attrs.rs)
 
-// Crate-level is accepted, though it is almost certainly unused?
-#![inline                     = "2100"]
+// Crate-level is rejected: it would not apply to any function.
+#![inline                     = "2100"] //~ ERROR cannot be applied to a crate
 
 #[inline = "2100"]
 //~^ ERROR attribute should be applied to function or closure
@@ -24,7 +24,7 @@ mod inline {
     mod inner { #![inline="2100"] }
     //~^ ERROR attribute should be applied to function or closure
 
-    #[inline = "2100"] fn f() { }
+    #[inline = "2100"] fn f() { } //~ ERROR attribute must be of the form
 
     #[inline = "2100"] struct S;
     //~^ ERROR attribute should be applied to function or closure
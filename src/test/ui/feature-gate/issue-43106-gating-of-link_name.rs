@@ -0,0 +1,43 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// This is testing whether `#[link_name]` signals an error or warning
+// when put in "weird" places.
+//
+// (This file sits on its own because it signals a
+// `misplaced_inert_attributes` warning for each case, which would mess
+// up the treatment of other cases in issue-43106-gating-of-builtin-attrs.rs)
+
+// compile-pass
+
+#[link_name = "1900"]
+//~^ WARN attribute should be applied to a foreign function or static
+mod link_name {
+    mod inner { #![link_name="1900"] }
+    //~^ WARN attribute should be applied to a foreign function or static
+
+    #[link_name = "1900"] fn f() { }
+    //~^ WARN attribute should be applied to a foreign function or static
+
+    #[link_name = "1900"] struct S;
+    //~^ WARN attribute should be applied to a foreign function or static
+
+    #[link_name = "1900"] type T = S;
+    //~^ WARN attribute should be applied to a foreign function or static
+
+    #[link_name = "1900"] impl S { }
+    //~^ WARN attribute should be applied to a foreign function or static
+}
+
+extern {
+    #[link_name = "1900"] fn g(); // OK
+}
+
+fn main() {}
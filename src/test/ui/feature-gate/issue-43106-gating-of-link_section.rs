@@ -0,0 +1,38 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// This is testing whether `#[link_section]` signals an error or warning
+// when put in "weird" places.
+//
+// (This file sits on its own because it signals a
+// `misplaced_inert_attributes` warning for each case, which would mess
+// up the treatment of other cases in issue-43106-gating-of-builtin-attrs.rs)
+
+// compile-pass
+
+#[link_section = "1800"]
+//~^ WARN attribute should be applied to a function or static
+mod link_section {
+    mod inner { #![link_section="1800"] }
+    //~^ WARN attribute should be applied to a function or static
+
+    #[link_section = "1800"] fn f() { }
+
+    #[link_section = "1800"] struct S;
+    //~^ WARN attribute should be applied to a function or static
+
+    #[link_section = "1800"] type T = S;
+    //~^ WARN attribute should be applied to a function or static
+
+    #[link_section = "1800"] impl S { }
+    //~^ WARN attribute should be applied to a function or static
+}
+
+fn main() {}
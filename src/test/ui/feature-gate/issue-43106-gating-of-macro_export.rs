@@ -0,0 +1,43 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// This is testing whether `#[macro_export]` signals an error or warning
+// when put in "weird" places.
+//
+// (This file sits on its own because it actually signals an error,
+// which would mess up the treatment of other cases in
+// issue-43106-gating-of-builtin-attrs.rs)
+
+// Crate-level is rejected: it would not apply to any macro.
+#![macro_export               = "4800"] //~ ERROR cannot be applied to a crate
+
+#[macro_export = "4800"]
+//~^ WARN attribute should be applied to a `macro_rules!` definition
+mod macro_export {
+    mod inner { #![macro_export="4800"] }
+    //~^ WARN attribute should be applied to a `macro_rules!` definition
+
+    #[macro_export = "4800"] fn f() { }
+    //~^ WARN attribute should be applied to a `macro_rules!` definition
+
+    #[macro_export = "4800"] struct S;
+    //~^ WARN attribute should be applied to a `macro_rules!` definition
+
+    #[macro_export = "4800"] type T = S;
+    //~^ WARN attribute should be applied to a `macro_rules!` definition
+
+    #[macro_export = "4800"] impl S { }
+    //~^ WARN attribute should be applied to a `macro_rules!` definition
+}
+
+#[macro_export = "4800"] //~ ERROR attribute must be of the form
+macro_rules! m { () => {} }
+
+fn main() {}
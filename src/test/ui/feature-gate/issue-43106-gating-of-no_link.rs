@@ -0,0 +1,40 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// This is testing whether `#[no_link]` signals an error or warning
+// when put in "weird" places.
+//
+// (This file sits on its own because it actually signals an error,
+// which would mess up the treatment of other cases in
+// issue-43106-gating-of-builtin-attrs.rs)
+
+// Crate-level is rejected: it would not apply to any `extern crate` item.
+#![no_link                    = "3400"] //~ ERROR cannot be applied to a crate
+
+#[no_link = "3400"]
+//~^ WARN attribute should be applied to an `extern crate` item
+mod no_link {
+    mod inner { #![no_link="3400"] }
+    //~^ WARN attribute should be applied to an `extern crate` item
+
+    #[no_link = "3400"] fn f() { }
+    //~^ WARN attribute should be applied to an `extern crate` item
+
+    #[no_link = "3400"] struct S;
+    //~^ WARN attribute should be applied to an `extern crate` item
+
+    #[no_link = "3400"] type T = S;
+    //~^ WARN attribute should be applied to an `extern crate` item
+
+    #[no_link = "3400"] impl S { }
+    //~^ WARN attribute should be applied to an `extern crate` item
+}
+
+fn main() {}
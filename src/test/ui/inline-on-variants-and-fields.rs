@@ -0,0 +1,26 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// `#[inline]` on a variant or a field is reported, with a suggestion to remove it.
+
+enum E {
+    A,
+    #[inline] //~ ERROR attribute should be applied to function or closure
+    B(u8),
+}
+
+struct S {
+    #[inline] //~ ERROR attribute should be applied to function or closure
+    a: u8,
+}
+
+struct T(#[inline] u8); //~ ERROR attribute should be applied to function or closure
+
+fn main() {}
@@ -0,0 +1,23 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// run-rustfix
+
+#![deny(multiple_repr_attributes)]
+#![allow(dead_code)]
+
+#[repr(C)] //~ ERROR representation hints are split across multiple `#[repr]` attributes
+#[repr(packed)]
+struct Foo(u8, u32);
+
+#[repr(C, packed)]
+struct Bar(u8, u32);
+
+fn main() {}
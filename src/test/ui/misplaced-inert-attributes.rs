@@ -0,0 +1,36 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// `#[cold]` does nothing where it can't be applied, so writing it there is a
+// lint that warns by default and can be allowed or denied. `#[used]` somewhere
+// else is still an error.
+
+#![allow(dead_code)]
+
+#[cold] //~ WARN attribute should be applied to a function or closure
+struct S;
+
+#[allow(misplaced_inert_attributes)]
+mod m {
+    #[cold]
+    struct T;
+}
+
+#[deny(misplaced_inert_attributes)]
+mod n {
+    #[cold] //~ ERROR attribute should be applied to a function or closure
+    struct U;
+}
+
+#[allow(misplaced_inert_attributes)]
+#[used] //~ ERROR attribute should be applied to a `static` variable
+fn g() {}
+
+fn main() {}
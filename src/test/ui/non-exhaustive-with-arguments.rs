@@ -0,0 +1,22 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// run-rustfix
+
+#![feature(non_exhaustive)]
+#![allow(dead_code)]
+
+#[non_exhaustive(foo)] //~ ERROR attribute must be of the form `#[non_exhaustive]`
+pub struct A { pub x: u32 }
+
+#[non_exhaustive = "bar"] //~ ERROR attribute must be of the form `#[non_exhaustive]`
+pub enum B { X }
+
+fn main() {}
@@ -0,0 +1,44 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// `align` on an enum combines with an integer representation, but doesn't hide
+// a conflict between integer representations.
+
+#![feature(repr_align_enum)]
+#![allow(dead_code)]
+
+#[repr(u8, align(4))] // ok
+enum Tagged {
+    A,
+    B(u8),
+}
+
+#[repr(C, align(4))] // ok
+enum CTagged {
+    A,
+    B(u8),
+}
+
+#[repr(u8, u16, align(4))] //~ WARN conflicting representation hints
+enum TwoInts {
+    A,
+}
+
+#[repr(C, u8, align(4))] //~ WARN conflicting representation hints
+enum CLike {
+    A,
+}
+
+#[repr(u8, packed, align(4))] //~ ERROR attribute should be applied to struct or union
+enum Packed {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-pass
+// Only the hints that conflict are pointed at.
+
+#![feature(repr_simd)]
+#![allow(dead_code)]
+
+#[repr(C, align(16), simd)] //~ WARN conflicting representation hints
+struct F32x4(f32, f32, f32, f32);
+
+#[repr(C, u8, u16)] //~ WARN conflicting representation hints
+enum E {
+    A(u8),
+}
+
+fn main() {}
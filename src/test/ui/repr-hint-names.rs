@@ -0,0 +1,22 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(repr_simd)]
+
+#[repr(packed, simd)]
+enum E { A }
+//~^^ ERROR `repr(packed)` attribute should be applied to struct or union
+//~| ERROR `repr(simd)` attribute should be applied to struct
+
+#[repr(C, align(8))]
+fn f() {}
+//~^^ ERROR `repr(C, align(8))` attribute should be applied to struct, enum or union
+
+fn main() {}
@@ -0,0 +1,20 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A `#[repr]` on something which is not a type is reported once, however many
+// hints it has.
+
+#[repr(C, align(8))] //~ ERROR attribute should be applied to struct, enum or union
+fn f() {}
+
+#[repr(C, packed, u8)] //~ ERROR attribute should be applied to struct, enum or union
+static S: u32 = 0;
+
+fn main() {}
@@ -0,0 +1,19 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[repr(packed)] //~ ERROR type has conflicting packed and align representation hints
+#[repr(align(8))]
+struct S(u32);
+
+#[repr(align(8))]
+#[repr(C, packed)] //~ ERROR type has conflicting packed and align representation hints
+struct T(u32);
+
+fn main() {}
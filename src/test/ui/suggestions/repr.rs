@@ -25,4 +25,8 @@ struct _C {}
 #[repr(C)]
 struct _D {}
 
+#[repr()]
+//^ WARN `repr` attribute has no hints
+struct _E {}
+
 fn main() {}
@@ -0,0 +1,41 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// edition:2018
+// ignore-arm
+// ignore-aarch64
+// ignore-wasm
+// ignore-emscripten
+// ignore-mips
+// ignore-powerpc
+// ignore-powerpc64
+// ignore-powerpc64le
+// ignore-s390x
+// ignore-sparc
+// ignore-sparc64
+
+#![feature(async_await, futures_api, target_feature)]
+
+#[target_feature(enable = "sse2")]
+//~^ ERROR can only be applied to `unsafe` function
+async fn foo() {}
+
+#[target_feature(enable = "sse2")]
+//~^ ERROR can only be applied to `unsafe` function
+pub async fn bar() {}
+
+#[target_feature(enable = "sse2")]
+//~^ ERROR can only be applied to `unsafe` function
+extern "C" fn baz() {}
+
+#[target_feature(enable = "sse2")]
+unsafe async fn ok() {}
+
+fn main() {}
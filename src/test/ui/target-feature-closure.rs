@@ -0,0 +1,30 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-arm
+// ignore-aarch64
+// ignore-wasm
+// ignore-emscripten
+// ignore-mips
+// ignore-powerpc
+// ignore-powerpc64
+// ignore-powerpc64le
+// ignore-s390x
+// ignore-sparc
+// ignore-sparc64
+
+// A closure can't be `unsafe`, so `#[target_feature]` can't be applied to it.
+
+#![feature(stmt_expr_attributes, target_feature)]
+
+fn main() {
+    let _f = #[target_feature(enable = "sse2")] || {};
+    //~^ ERROR attribute should be applied to a function
+}
@@ -41,7 +41,7 @@ fn bar() {}
 mod another {}
 
 #[inline(always)]
-//~^ ERROR: cannot use #[inline(always)]
+//~^ ERROR: conflicting codegen attributes
 #[target_feature(enable = "sse2")]
 unsafe fn test() {}
 
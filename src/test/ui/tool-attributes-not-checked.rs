@@ -0,0 +1,29 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-pass
+
+// Tool attributes are never checked against their target, even when their
+// last segment is the name of a built-in attribute.
+
+#![feature(tool_attributes)]
+
+#[rustfmt::skip]
+#[clippy::inline]
+struct Foo;
+
+#[clippy::repr]
+fn bar() {}
+
+fn main() {
+    #[clippy::repr]
+    let _x = Foo;
+    bar();
+}
@@ -10,20 +10,20 @@
 
 #![feature(wasm_custom_section)]
 
-#[wasm_custom_section = "foo"] //~ ERROR: only allowed on consts
+#[wasm_custom_section = "foo"] //~ ERROR: should be applied to a constant
 static A: [u8; 2] = [1, 2];
 
-#[wasm_custom_section = "foo"] //~ ERROR: only allowed on consts
+#[wasm_custom_section = "foo"] //~ ERROR: should be applied to a constant
 struct B {}
 
-#[wasm_custom_section = "foo"] //~ ERROR: only allowed on consts
+#[wasm_custom_section = "foo"] //~ ERROR: should be applied to a constant
 enum C {}
 
-#[wasm_custom_section = "foo"] //~ ERROR: only allowed on consts
+#[wasm_custom_section = "foo"] //~ ERROR: should be applied to a constant
 impl B {}
 
-#[wasm_custom_section = "foo"] //~ ERROR: only allowed on consts
+#[wasm_custom_section = "foo"] //~ ERROR: should be applied to a constant
 mod d {}
 
-#[wasm_custom_section = "foo"] //~ ERROR: only allowed on consts
+#[wasm_custom_section = "foo"] //~ ERROR: should be applied to a constant
 fn main() {}
@@ -13,7 +13,7 @@
 #[wasm_import_module] //~ ERROR: must be of the form
 extern {}
 
-#[wasm_import_module = "foo"] //~ ERROR: must only be attached to
+#[wasm_import_module = "foo"] //~ ERROR: should be applied to an `extern` block
 fn foo() {}
 
 fn main() {}